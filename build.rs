@@ -0,0 +1,59 @@
+//! Collects every solution in `src/bin` so the main binary can run them in-process.
+//! Each `src/bin/{year}-{day}.rs` is included as a module and its `SOLUTION` constant
+//! (created by the `solution!` macro) is listed in `SOLUTIONS`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut bins: Vec<String> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter_map(|name| name.strip_suffix(".rs").map(ToString::to_string))
+                .filter(|name| is_solution_name(name))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    bins.sort();
+
+    let mut out = String::new();
+
+    for bin in &bins {
+        let path = bin_dir.join(format!("{bin}.rs"));
+        out.push_str(&format!(
+            "#[path = {:?}]\n#[allow(dead_code)]\nmod solution_{};\n",
+            path.display().to_string(),
+            bin.replace('-', "_")
+        ));
+    }
+
+    out.push_str("\npub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n");
+    for bin in &bins {
+        out.push_str(&format!(
+            "    solution_{}::SOLUTION,\n",
+            bin.replace('-', "_")
+        ));
+    }
+    out.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, out).unwrap();
+}
+
+/// Solution binaries are named `{year}-{day}`, e.g. `2023-01`.
+fn is_solution_name(name: &str) -> bool {
+    let Some((year, day)) = name.split_once('-') else {
+        return false;
+    };
+
+    year.len() == 4
+        && day.len() == 2
+        && year.chars().all(|c| c.is_ascii_digit())
+        && day.chars().all(|c| c.is_ascii_digit())
+}
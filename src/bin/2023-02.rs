@@ -1,4 +1,4 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2);
 
 #[derive(Clone, Copy)]
 #[repr(u8)]
@@ -1,25 +1,17 @@
-advent_of_code::solution!(3);
-
-const ADJACENT: &[(isize, isize)] = &[
-    (0, 1),
-    (1, 0),
-    (0, -1),
-    (-1, 0),
-    (1, 1),
-    (1, -1),
-    (-1, 1),
-    (-1, -1),
-];
+use advent_of_code::grid::{Grid, Point};
+
+advent_of_code::solution!(2023, 3);
+
+fn parse_schematic(input: &str) -> Grid<char> {
+    Grid::parse(input, |c| c).expect("rectangular schematic")
+}
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let schematic = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+    let schematic = parse_schematic(input);
 
     Some(
         schematic
-            .iter()
+            .rows()
             .enumerate()
             .flat_map(|(y, line)| {
                 let mut line = line.iter().enumerate().peekable();
@@ -29,14 +21,9 @@ pub fn part_one(input: &str) -> Option<u32> {
 
                 while let Some((x, c)) = line.next() {
                     if c.is_numeric() {
-                        let adjacent = ADJACENT
-                            .iter()
-                            .cloned()
-                            .map(|(dx, dy)| {
-                                (x.saturating_add_signed(dx), y.saturating_add_signed(dy))
-                            })
-                            .filter_map(|(x, y)| schematic.get(y).and_then(|line| line.get(x)))
-                            .any(|&c| c != '.' && !c.is_numeric());
+                        let adjacent = schematic
+                            .neighbours8(Point::new(x, y))
+                            .any(|point| schematic[point] != '.' && !schematic[point].is_numeric());
 
                         let n = c.to_digit(10).unwrap();
 
@@ -64,35 +51,23 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let schematic = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+    let schematic = parse_schematic(input);
 
     let mut gear_numbers = schematic
-        .iter()
+        .rows()
         .enumerate()
         .flat_map(|(y, line)| {
             let mut line = line.iter().enumerate().peekable();
 
             let mut numbers = Vec::new();
-            let mut current_number: Option<(u32, Option<(usize, usize)>)> = None;
+            let mut current_number: Option<(u32, Option<Point>)> = None;
 
             while let Some((x, c)) = line.next() {
                 if c.is_numeric() {
                     // Check if number is adjacent to any gears
-                    let adjacent_gear = ADJACENT
-                        .iter()
-                        .cloned()
-                        .map(|(dx, dy)| (x.saturating_add_signed(dx), y.saturating_add_signed(dy)))
-                        .filter_map(|(x, y)| {
-                            schematic
-                                .get(y)
-                                .and_then(|line| line.get(x))
-                                .map(|c| (x, y, c))
-                        })
-                        .find(|(_, _, &c)| c == '*')
-                        .map(|(x, y, _)| (x, y));
+                    let adjacent_gear = schematic
+                        .neighbours8(Point::new(x, y))
+                        .find(|&point| schematic[point] == '*');
 
                     let n = c.to_digit(10).unwrap();
 
@@ -117,7 +92,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         })
         .collect::<Vec<_>>();
 
-    gear_numbers.sort_unstable_by_key(|(gear, _)| gear.clone());
+    gear_numbers.sort_unstable_by_key(|(gear, _)| *gear);
 
     let mut total = 0;
     let mut iter = gear_numbers.into_iter().peekable();
@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2023, 4);
 
 fn parse(input: &str) -> impl Iterator<Item = (Vec<u32>, Vec<u32>)> + '_ {
     input
@@ -25,12 +25,12 @@ fn get_winning_numbers(mut selected: Vec<u32>, mut valid: Vec<u32>) -> Vec<u32>
     selected.sort_unstable();
     valid.sort_unstable();
 
-    let mut selected = selected.into_iter();
+    let selected = selected.into_iter();
     let mut valid = valid.into_iter().peekable();
 
     let mut winning = Vec::new();
 
-    while let Some(n) = selected.next() {
+    for n in selected {
         while valid.peek().map(|&next| next < n).unwrap_or_default() {
             valid.next();
         }
@@ -73,7 +73,7 @@ pub fn part_two(input: &str) -> Option<u32> {
                         multipliers.push_back(1);
                     }
 
-                    (0..winning).into_iter().for_each(|i| {
+                    (0..winning).for_each(|i| {
                         multipliers[i as usize] += multiplier;
                     });
 
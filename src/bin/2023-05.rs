@@ -0,0 +1,96 @@
+use advent_of_code::ranges::{Interval, IntervalSet, PiecewiseMap};
+
+advent_of_code::solution!(2023, 5);
+
+struct RangeMap {
+    destination_start: i64,
+    source_start: i64,
+    length: i64,
+}
+
+impl TryFrom<&str> for RangeMap {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut iter = value.split_whitespace().flat_map(|n| n.parse::<i64>());
+
+        Ok(Self {
+            destination_start: iter.next().ok_or(())?,
+            source_start: iter.next().ok_or(())?,
+            length: iter.next().ok_or(())?,
+        })
+    }
+}
+
+impl From<RangeMap> for (Interval, i64) {
+    fn from(range: RangeMap) -> Self {
+        (
+            Interval::new(range.source_start, range.source_start + range.length),
+            range.destination_start - range.source_start,
+        )
+    }
+}
+
+/// Parses the seeds, and the map from seeds to locations that applies every map in turn.
+fn parse_almanac(input: &str) -> Option<(Vec<i64>, PiecewiseMap)> {
+    let mut iter = input.split("\n\n");
+
+    let seeds = iter
+        .next()?
+        .split_once(": ")?
+        .1
+        .split_whitespace()
+        .flat_map(|n| n.parse::<i64>())
+        .collect::<Vec<_>>();
+
+    let almanac = iter
+        .map(|map| {
+            map.lines()
+                .skip(1)
+                .flat_map(RangeMap::try_from)
+                .map(<(Interval, i64)>::from)
+                .collect::<PiecewiseMap>()
+        })
+        .fold(PiecewiseMap::identity(), |almanac, map| almanac.then(&map));
+
+    Some((seeds, almanac))
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (seeds, almanac) = parse_almanac(input)?;
+
+    seeds
+        .into_iter()
+        .map(|seed| almanac.apply(seed))
+        .min()
+        .map(|n| n as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let (seeds, almanac) = parse_almanac(input)?;
+
+    let seeds = seeds
+        .chunks(2)
+        .map(|chunk| Interval::new(chunk[0], chunk[0] + chunk[1]))
+        .collect::<IntervalSet>();
+
+    // Find the smallest location any seed ends up at
+    almanac.apply_set(&seeds).min().map(|n| n as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(35));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(46));
+    }
+}
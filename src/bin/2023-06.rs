@@ -1,4 +1,4 @@
-advent_of_code::solution!(6);
+advent_of_code::solution!(2023, 6);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let mut iter = input.lines().map(|line| {
@@ -1,4 +1,4 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7);
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy)]
@@ -81,7 +81,7 @@ fn run_game(hands: impl Iterator<Item = ([u32; 5], u32)>) -> u32 {
         .map(|(hand, bid)| (HandType::from(&hand), hand, bid))
         .collect::<Vec<_>>();
 
-    hands.sort_unstable_by_key(|(ty, hand, _)| (*ty as u32, hand.clone()));
+    hands.sort_unstable_by_key(|(ty, hand, _)| (*ty as u32, *hand));
 
     hands
         .into_iter()
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(8);
+use advent_of_code::math;
+
+advent_of_code::solution!(2023, 8);
 
 #[derive(Hash, Eq, PartialEq, Clone, Copy)]
 struct NodeIdentifier(char, char, char);
@@ -99,7 +101,7 @@ fn run(
 ) -> u32 {
     let mut position = start;
     let mut step_count = 0;
-    let mut directions = directions.into_iter().cycle();
+    let mut directions = directions.iter().cycle();
 
     while !end_test(&position) {
         let node = nodes.get(&position).expect("node present in nodes list");
@@ -126,17 +128,96 @@ pub fn part_one(input: &str) -> Option<u32> {
     ))
 }
 
+/// The steps at which a ghost starting at `start` is on an end node: the steps before its walk
+/// starts repeating, and the `(offset, period)` cycles of the ones after.
+fn ghost_hits(
+    start: NodeIdentifier,
+    directions: &[Direction],
+    nodes: &HashMap<NodeIdentifier, (NodeIdentifier, NodeIdentifier)>,
+) -> (Vec<u64>, Vec<(u64, u64)>) {
+    // The walk repeats once it is back on a node at the same point in the directions
+    let mut seen = HashMap::new();
+    let mut hits = vec![];
+    let mut position = start;
+
+    for (step, (i, direction)) in directions.iter().enumerate().cycle().enumerate() {
+        let step = step as u64;
+
+        if let Some(&cycle_start) = seen.get(&(position, i)) {
+            let period = step - cycle_start;
+            let (before, during): (Vec<u64>, Vec<u64>) =
+                hits.into_iter().partition(|&hit| hit < cycle_start);
+
+            return (
+                before,
+                during.into_iter().map(|hit| (hit, period)).collect(),
+            );
+        }
+
+        seen.insert((position, i), step);
+
+        if position.is_ghost_end() {
+            hits.push(step);
+        }
+
+        let node = nodes.get(&position).expect("node present in nodes list");
+
+        position = match direction {
+            Direction::Left => node.0,
+            Direction::Right => node.1,
+        };
+    }
+
+    unreachable!("directions repeat forever")
+}
+
 pub fn part_two(input: &str) -> Option<u64> {
     let (directions, nodes) = parse(input);
 
-    Some(
-        nodes
-            .keys()
-            .cloned()
-            .filter(NodeIdentifier::is_ghost_start)
-            .map(|position| run(position, NodeIdentifier::is_ghost_end, &directions, &nodes) as u64)
-            .fold(1u64, num::integer::lcm),
-    )
+    let ghosts = nodes
+        .keys()
+        .cloned()
+        .filter(NodeIdentifier::is_ghost_start)
+        .map(|position| ghost_hits(position, &directions, &nodes))
+        .collect::<Vec<_>>();
+
+    let is_hit = |(before, cycles): &(Vec<u64>, Vec<(u64, u64)>), step: u64| {
+        before.contains(&step)
+            || cycles
+                .iter()
+                .any(|&(offset, period)| step >= offset && (step - offset).is_multiple_of(period))
+    };
+
+    // Every ghost being on an end node before all of them are walking in circles
+    let early = ghosts
+        .iter()
+        .flat_map(|(before, _)| before)
+        .filter(|&&step| ghosts.iter().all(|ghost| is_hit(ghost, step)))
+        .min()
+        .copied();
+
+    // Otherwise, the first time a cycle of each ghost lines up
+    let combinations = ghosts
+        .iter()
+        .fold(vec![vec![]], |combinations, (_, cycles)| {
+            combinations
+                .iter()
+                .flat_map(|combination: &Vec<(u64, u64)>| {
+                    cycles.iter().map(|&cycle| {
+                        let mut combination = combination.clone();
+                        combination.push(cycle);
+                        combination
+                    })
+                })
+                .collect()
+        });
+
+    let cyclic = combinations
+        .into_iter()
+        .filter_map(math::first_common_hit)
+        .min();
+
+    early.into_iter().chain(cyclic).min()
 }
 
 #[cfg(test)]
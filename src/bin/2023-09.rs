@@ -1,4 +1,4 @@
-advent_of_code::solution!(9);
+advent_of_code::solution!(2023, 9);
 
 fn solve(input: &str, operation: fn(sequence: &[i64], next_difference: i64) -> i64) -> i64 {
     input
@@ -30,7 +30,7 @@ fn solve(input: &str, operation: fn(sequence: &[i64], next_difference: i64) -> i
             sequences
                 .iter()
                 .rev()
-                .fold(0, |next_value, sequence| operation(&sequence, next_value))
+                .fold(0, |next_value, sequence| operation(sequence, next_value))
         })
         .sum()
 }
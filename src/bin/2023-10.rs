@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use advent_of_code::grid::{Grid, Point};
+use advent_of_code::search::reachable;
+
+advent_of_code::solution!(2023, 10);
+
+#[derive(Clone, Copy, Debug)]
+enum Tile {
+    Vertical,
+    Horizontal,
+    NE,
+    NW,
+    SW,
+    SE,
+    Start,
+    Ground,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            'L' => Ok(Self::NE),
+            'J' => Ok(Self::NW),
+            '7' => Ok(Self::SW),
+            'F' => Ok(Self::SE),
+            'S' => Ok(Self::Start),
+            '.' => Ok(Self::Ground),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Tile {
+    fn adjacent_coords(self: &Tile) -> &[(isize, isize)] {
+        match self {
+            Self::Vertical => &[(0, 1), (0, -1)],
+            Self::Horizontal => &[(1, 0), (-1, 0)],
+            Self::NE => &[(0, -1), (1, 0)],
+            Self::NW => &[(0, -1), (-1, 0)],
+            Self::SW => &[(0, 1), (-1, 0)],
+            Self::SE => &[(0, 1), (1, 0)],
+            Self::Start => &[(0, 1), (1, 0), (0, -1), (-1, 0)],
+            Self::Ground => &[],
+        }
+    }
+}
+
+fn parse_map(input: &str) -> Grid<Tile> {
+    Grid::parse(input, |c| Tile::try_from(c).expect("valid pipe map")).expect("rectangular map")
+}
+
+/// How many steps it takes to reach each tile of the loop from the start, following the pipes.
+fn loop_distances(map: &Grid<Tile>) -> HashMap<Point, usize> {
+    let start = map
+        .position(|tile| matches!(tile, Tile::Start))
+        .expect("starting location");
+
+    reachable([start], |&pos| {
+        map[pos]
+            .adjacent_coords()
+            .iter()
+            .filter_map(move |&offset| map.offset(pos, offset))
+    })
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let map = parse_map(input);
+
+    loop_distances(&map)
+        .into_values()
+        .max()
+        .map(|distance| distance as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let loop_tiles = loop_distances(&parse_map(input));
+
+    let map = {
+        let mut map = parse_map(input);
+
+        // Find and replace start with the relevant piece
+        let start_pos = map
+            .position(|tile| matches!(tile, Tile::Start))
+            .expect("start position");
+
+        let start_tile = [
+            ((0isize, 1isize), (0isize, -1isize), Tile::Vertical),
+            ((1, 0), (-1, 0), Tile::Horizontal),
+            ((1, 0), (0, -1), Tile::NE),
+            ((-1, 0), (0, -1), Tile::NW),
+            ((1, 0), (0, 1), Tile::SE),
+            ((-1, 0), (0, 1), Tile::SW),
+        ]
+        .into_iter()
+        .flat_map(|(offset_1, offset_2, tile)| {
+            Some((
+                map[map.offset(start_pos, offset_1)?],
+                map[map.offset(start_pos, offset_2)?],
+                tile,
+            ))
+        })
+        .filter(|(tile_1, tile_2, _)| {
+            !matches!(tile_1, Tile::Ground) && !matches!(tile_2, Tile::Ground)
+        })
+        .map(|(_, _, tile)| tile)
+        .next()
+        .expect("equivalent start tile");
+
+        map[start_pos] = start_tile;
+
+        map
+    };
+
+    let mut count = 0;
+
+    for (y, line) in map.rows().enumerate() {
+        // Whether currently tracing a line
+        let mut inside = false;
+
+        // Tile that was used to enter a line
+        let mut line_entry = None;
+
+        for (x, tile) in line.iter().enumerate() {
+            match (tile, loop_tiles.contains_key(&Point::new(x, y))) {
+                (Tile::Ground, _) | (_, false) => {
+                    if inside {
+                        count += 1;
+                    }
+
+                    if line_entry.is_some() {
+                        line_entry = None;
+                    }
+                }
+                (Tile::Horizontal | Tile::Vertical, true) => {
+                    if line_entry.is_some() {
+                        continue;
+                    } else {
+                        inside = !inside;
+                    }
+                }
+                (Tile::NE | Tile::NW | Tile::SE | Tile::SW, true) => {
+                    if let Some(entry) = line_entry {
+                        if ((matches!(entry, Tile::NW) || matches!(entry, Tile::NE))
+                            && (matches!(tile, Tile::SE) || matches!(tile, Tile::SW)))
+                            || ((matches!(entry, Tile::SW) || matches!(entry, Tile::SE))
+                                && (matches!(tile, Tile::NE) || matches!(tile, Tile::NW)))
+                        {
+                            // Entry is pointing opposite direction to exit, changing shape side
+                            inside = !inside;
+                        }
+
+                        line_entry = None;
+                    } else {
+                        line_entry = Some(*tile);
+                    }
+                }
+                (Tile::Start, true) => unreachable!("start tile should be removed"),
+            }
+        }
+    }
+
+    Some(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::template::assert_examples;
+
+    #[test]
+    fn test_part_one() {
+        assert_examples(DAY, part_one, &[("", Some(8))]);
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_examples(DAY, part_two, &[("2", Some(10))]);
+    }
+}
@@ -1,4 +1,4 @@
-advent_of_code::solution!(11);
+advent_of_code::solution!(2023, 11);
 
 fn solve(input: &str, expansion_factor: u64) -> u64 {
     let universe = input
@@ -9,8 +9,7 @@ fn solve(input: &str, expansion_factor: u64) -> u64 {
     // Expand universe
     let row_factors = universe
         .iter()
-        .enumerate()
-        .map(|(_, row)| {
+        .map(|row| {
             if row.iter().all(|c| !c) {
                 // Is empty
                 expansion_factor
@@ -32,11 +31,11 @@ fn solve(input: &str, expansion_factor: u64) -> u64 {
     let galaxies = universe
         .iter()
         .enumerate()
-        .map(|(y, row)| ((0..y).map(|y| row_factors[y as usize]).sum::<u64>(), row))
+        .map(|(y, row)| ((0..y).map(|y| row_factors[y]).sum::<u64>(), row))
         .flat_map(|(y, row)| {
             row.iter()
                 .enumerate()
-                .map(|(x, c)| ((0..x).map(|x| col_factors[x as usize]).sum::<u64>(), c))
+                .map(|(x, c)| ((0..x).map(|x| col_factors[x]).sum::<u64>(), c))
                 .filter(|(_, c)| **c)
                 .map(move |(x, _)| (x, y))
         })
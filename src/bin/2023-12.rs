@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(12);
+advent_of_code::solution!(2023, 12);
 
 #[derive(Clone, Copy, Debug)]
 enum Spring {
@@ -94,7 +94,7 @@ fn solve(input: &str, repeat: usize) -> u64 {
                     } else if state.springs_i < self.springs.len()
                         && state.groups_i <= self.groups.len()
                     {
-                        let current_spring = self.springs[state.springs_i].clone();
+                        let current_spring = self.springs[state.springs_i];
 
                         if matches!(current_spring, Spring::Working | Spring::Unknown) {
                             if state.group_remaining == 0 {
@@ -1,4 +1,6 @@
-advent_of_code::solution!(13);
+use advent_of_code::grid::{Grid, Point};
+
+advent_of_code::solution!(2023, 13);
 
 fn find_reflection(
     col_max: usize,
@@ -14,7 +16,6 @@ fn find_reflection(
         };
 
         (0..distance)
-            .into_iter()
             .map(|d| {
                 (0..col_max)
                     .filter(|&col| get(col, row + d) != get(col, row - d - 1))
@@ -29,28 +30,22 @@ fn solve(input: &str, variance: usize) -> u32 {
     input
         .split("\n\n")
         .map(|section| {
-            section
-                .lines()
-                .map(|line| {
-                    line.chars()
-                        .map(|c| match c {
-                            '#' => true,
-                            '.' => false,
-                            _ => unreachable!("invalid character in input"),
-                        })
-                        .collect::<Vec<_>>()
-                })
-                .collect::<Vec<_>>()
+            Grid::parse(section, |c| match c {
+                '#' => true,
+                '.' => false,
+                _ => unreachable!("invalid character in input"),
+            })
+            .expect("rectangular pattern")
         })
         .map(|section| {
-            if let Some(x) = find_reflection(section.len(), section[0].len(), variance, |y, x| {
-                section[y][x]
+            if let Some(x) = find_reflection(section.height(), section.width(), variance, |y, x| {
+                section[Point::new(x, y)]
             }) {
                 return x as u32;
             }
 
-            if let Some(y) = find_reflection(section[0].len(), section.len(), variance, |x, y| {
-                section[y][x]
+            if let Some(y) = find_reflection(section.width(), section.height(), variance, |x, y| {
+                section[Point::new(x, y)]
             }) {
                 return y as u32 * 100;
             }
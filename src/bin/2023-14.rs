@@ -0,0 +1,100 @@
+use advent_of_code::cycle::state_after;
+use advent_of_code::grid::{Direction, Grid, Point};
+
+advent_of_code::solution!(2023, 14);
+
+fn tilt(map: &mut Grid<Option<bool>>, direction: Direction) {
+    let (dx, dy) = direction.offset();
+
+    for mut y in 0..map.height() {
+        if dy == 1 {
+            y = map.height() - y - 1;
+        }
+
+        for mut x in 0..map.width() {
+            if dx == 1 {
+                x = map.width() - x - 1;
+            }
+
+            let rock = Point::new(x, y);
+
+            if matches!(map[rock], Some(true)) {
+                let mut new_position = rock;
+
+                // Roll until hitting the edge or another rock
+                while let Some(next) = map
+                    .step(new_position, direction)
+                    .filter(|&next| map[next].is_none())
+                {
+                    new_position = next;
+                }
+
+                map[rock] = None;
+                map[new_position] = Some(true);
+            }
+        }
+    }
+}
+
+fn solve(input: &str, rotations: &[Direction], cycles: usize) -> u32 {
+    let map = Grid::parse(input, |c| match c {
+        'O' => Some(true),
+        '#' => Some(false),
+        '.' => None,
+        _ => unreachable!("invalid character in input"),
+    })
+    .expect("rectangular map");
+
+    // Each direction north, west, south, east
+    let map = state_after(
+        map,
+        |map| {
+            let mut map = map.clone();
+            for &direction in rotations {
+                tilt(&mut map, direction);
+            }
+            map
+        },
+        cycles,
+    );
+
+    map.rows()
+        .rev()
+        .enumerate()
+        .map(|(weight, row)| row.iter().filter(|c| matches!(c, Some(true))).count() * (weight + 1))
+        .sum::<usize>() as u32
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Some(solve(input, &[Direction::North], 1))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(solve(
+        input,
+        &[
+            Direction::North,
+            Direction::West,
+            Direction::South,
+            Direction::East,
+        ],
+        1_000_000_000,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(136));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(64));
+    }
+}
@@ -1,4 +1,4 @@
-advent_of_code::solution!(15);
+advent_of_code::solution!(2023, 15);
 
 fn hash(s: &str) -> u32 {
     s.chars()
@@ -10,7 +10,7 @@ fn hash(s: &str) -> u32 {
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    Some(input.split(',').map(|step| hash(step)).sum())
+    Some(input.split(',').map(hash).sum())
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
@@ -44,7 +44,7 @@ pub fn part_two(input: &str) -> Option<u32> {
                         if let Some(matching_i) = lens
                             .iter()
                             .enumerate()
-                            .find(|(_, (lens_label, _))| lens_label == &label)
+                            .find(|(_, (lens_label, _))| lens_label == label)
                             .map(|(slot_i, _)| slot_i)
                         {
                             lens.remove(matching_i);
@@ -0,0 +1,163 @@
+use std::collections::{HashMap, HashSet};
+
+use advent_of_code::grid::{Direction, Grid, Point};
+use advent_of_code::search::reachable;
+
+advent_of_code::solution!(2023, 16);
+
+#[derive(Clone, Copy)]
+enum Tile {
+    Empty,        // .
+    HSplit,       // -
+    VSplit,       // |
+    ForwardSlash, // /
+    BackSlash,    // \
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Empty),
+            '-' => Ok(Self::HSplit),
+            '|' => Ok(Self::VSplit),
+            '/' => Ok(Self::ForwardSlash),
+            '\\' => Ok(Self::BackSlash),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The directions a beam moving in `direction` continues in after passing through `tile`.
+fn deflect(tile: Tile, direction: Direction) -> (Direction, Option<Direction>) {
+    match tile {
+        // Approaching from above or below, split
+        Tile::HSplit if !direction.is_horizontal() => (Direction::West, Some(Direction::East)),
+        // Approaching from left or right, split
+        Tile::VSplit if direction.is_horizontal() => (Direction::North, Some(Direction::South)),
+        Tile::ForwardSlash => {
+            let direction = match direction {
+                Direction::North => Direction::East,
+                Direction::East => Direction::North,
+                Direction::South => Direction::West,
+                Direction::West => Direction::South,
+            };
+            (direction, None)
+        }
+        Tile::BackSlash => {
+            let direction = match direction {
+                Direction::North => Direction::West,
+                Direction::East => Direction::South,
+                Direction::South => Direction::East,
+                Direction::West => Direction::North,
+            };
+            (direction, None)
+        }
+        // Continue along in the same direction
+        _ => (direction, None),
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let map = Grid::parse(input, |c| Tile::try_from(c).expect("valid character"))
+        .expect("rectangular map");
+    let map = &map;
+
+    let beams = reachable(
+        [(Point::new(0, 0), Direction::East)],
+        |&(beam, direction)| {
+            let (first, second) = deflect(map[beam], direction);
+
+            [first]
+                .into_iter()
+                .chain(second)
+                .filter_map(move |direction| Some((map.step(beam, direction)?, direction)))
+        },
+    );
+
+    // Tiles are energised once light has passed through them
+    let energised: HashSet<Point> = beams.into_keys().map(|(beam, _)| beam).collect();
+
+    Some(energised.len() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let map = Grid::parse(input, |c| Tile::try_from(c).expect("valid character"))
+        .expect("rectangular map");
+
+    type BeamCache = HashMap<(Point, Direction), Option<HashSet<Point>>>;
+
+    fn solve(
+        map: &Grid<Tile>,
+        cache: &mut BeamCache,
+        beam: Point,
+        direction: Direction,
+    ) -> HashSet<Point> {
+        if let Some(maybe_path) = cache.get(&(beam, direction)) {
+            if let Some(path) = maybe_path {
+                path.clone()
+            } else {
+                // This branch is already being tested, may be in a loop
+                HashSet::new()
+            }
+        } else {
+            // Mark that this is being tested to prevent loops
+            cache.insert((beam, direction), None);
+
+            let mut path = HashSet::from_iter([beam]);
+
+            let (first, second) = deflect(map[beam], direction);
+
+            for direction in [first].into_iter().chain(second) {
+                if let Some(next) = map.step(beam, direction) {
+                    path.extend(solve(map, cache, next, direction));
+                }
+            }
+
+            cache.insert((beam, direction), Some(path.clone()));
+
+            path
+        }
+    }
+
+    let (width, height) = (map.width(), map.height());
+
+    let max = [
+        (0..width)
+            .map(|x| (Point::new(x, 0), Direction::South))
+            .collect::<Vec<_>>(),
+        (0..width)
+            .map(|x| (Point::new(x, height - 1), Direction::North))
+            .collect::<Vec<_>>(),
+        (0..height)
+            .map(|y| (Point::new(0, y), Direction::East))
+            .collect::<Vec<_>>(),
+        (0..height)
+            .map(|y| (Point::new(width - 1, y), Direction::West))
+            .collect::<Vec<_>>(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|(beam, direction)| solve(&map, &mut HashMap::new(), beam, direction))
+    .max_by_key(|path| path.len());
+
+    max.map(|max| max.len() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(46));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(51));
+    }
+}
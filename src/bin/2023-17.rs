@@ -0,0 +1,107 @@
+use advent_of_code::grid::{Direction, Grid, Point};
+use advent_of_code::search::{dijkstra, SearchConfig};
+
+advent_of_code::solution!(2023, 17, parse);
+
+/// The directions to either side of `direction`.
+fn turns(direction: Direction) -> [Direction; 2] {
+    if direction.is_horizontal() {
+        [Direction::North, Direction::South]
+    } else {
+        [Direction::East, Direction::West]
+    }
+}
+
+type Map = Grid<u32>;
+
+pub fn parse(input: &str) -> Map {
+    Grid::parse(input, |c| c.to_digit(10).expect("valid digit")).expect("rectangular map")
+}
+
+/// A crucible's position, how far it has moved in a straight line, and the direction it is moving in.
+type State = (Point, u32, Direction);
+
+/// The least heat lost on the way to the bottom right, moving at least `min_straight` and at
+/// most `max_straight` blocks in a straight line.
+fn min_heat_loss(
+    map: &Map,
+    starts: [(State, u32); 2],
+    min_straight: u32,
+    max_straight: u32,
+) -> Option<u32> {
+    let goal = Point::new(map.width() - 1, map.height() - 1);
+
+    dijkstra(
+        starts,
+        |&(position, distance, direction)| {
+            // Rotate direction and continue in both directions
+            turns(direction)
+                .into_iter()
+                // Only turn after the minimum number of steps
+                .filter(move |_| distance >= min_straight)
+                .map(|direction| (direction, 1))
+                // Continue in same direction
+                .chain((distance < max_straight).then_some((direction, distance + 1)))
+                .filter_map(move |(direction, distance)| {
+                    // Make sure doesn't go past bounds of map
+                    let position = map.step(position, direction)?;
+
+                    Some(((position, distance, direction), map[position]))
+                })
+        },
+        |&(position, distance, _)| position == goal && distance >= min_straight,
+        SearchConfig::default(),
+    )
+    .map(|found| found.cost)
+}
+
+pub fn part_one(map: &Map) -> Option<u32> {
+    min_heat_loss(
+        map,
+        [
+            ((Point::new(0, 0), 1, Direction::East), 0),
+            ((Point::new(0, 0), 1, Direction::South), 0),
+        ],
+        0,
+        3,
+    )
+}
+
+pub fn part_two(map: &Map) -> Option<u32> {
+    min_heat_loss(
+        map,
+        [
+            (
+                (Point::new(3, 0), 4, Direction::East),
+                map.row(0)[1..=3].iter().sum(),
+            ),
+            (
+                (Point::new(0, 3), 4, Direction::South),
+                map.column(0).skip(1).take(3).sum(),
+            ),
+        ],
+        4,
+        10,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
+        assert_eq!(result, Some(102));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
+        assert_eq!(result, Some(94));
+    }
+}
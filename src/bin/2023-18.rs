@@ -1,51 +1,26 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(18);
-
-#[derive(Clone, Copy, Debug)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-impl From<Direction> for (isize, isize) {
-    fn from(direction: Direction) -> Self {
-        match direction {
-            Direction::Up => (0, -1),
-            Direction::Right => (1, 0),
-            Direction::Down => (0, 1),
-            Direction::Left => (-1, 0),
-        }
-    }
-}
+use advent_of_code::grid::Direction;
 
-impl TryFrom<char> for Direction {
-    type Error = ();
+advent_of_code::solution!(2023, 18);
 
-    fn try_from(c: char) -> Result<Self, Self::Error> {
-        match c {
-            'U' => Ok(Self::Up),
-            'R' => Ok(Self::Right),
-            'D' => Ok(Self::Down),
-            'L' => Ok(Self::Left),
-            _ => Err(()),
-        }
+fn parse_direction(c: char) -> Option<Direction> {
+    match c {
+        'U' => Some(Direction::North),
+        'R' => Some(Direction::East),
+        'D' => Some(Direction::South),
+        'L' => Some(Direction::West),
+        _ => None,
     }
 }
 
-impl TryFrom<u32> for Direction {
-    type Error = ();
-
-    fn try_from(c: u32) -> Result<Self, Self::Error> {
-        match c {
-            0 => Ok(Self::Right),
-            1 => Ok(Self::Down),
-            2 => Ok(Self::Left),
-            3 => Ok(Self::Up),
-            _ => Err(()),
-        }
+fn decode_direction(digit: u32) -> Option<Direction> {
+    match digit {
+        0 => Some(Direction::East),
+        1 => Some(Direction::South),
+        2 => Some(Direction::West),
+        3 => Some(Direction::North),
+        _ => None,
     }
 }
 
@@ -56,7 +31,7 @@ fn solve(corners: impl Iterator<Item = (Direction, u128)>) -> u128 {
     let corners = {
         let mut corners = corners
             .scan((0isize, 0isize), |(x, y), (direction, distance)| {
-                let (dx, dy) = direction.into();
+                let (dx, dy) = direction.offset();
 
                 let result = Some((*x, *y));
 
@@ -159,7 +134,7 @@ pub fn part_one(input: &str) -> Option<u128> {
         let mut iter = line.split_whitespace();
 
         let direction =
-            Direction::try_from(iter.next().expect("direction").chars().next().unwrap()).unwrap();
+            parse_direction(iter.next().expect("direction").chars().next().unwrap()).unwrap();
         let distance = iter
             .next()
             .expect("distance")
@@ -187,7 +162,7 @@ pub fn part_two(input: &str) -> Option<u128> {
             .map(|i| encoded.next().expect("distance digit") as u128 * 16u128.pow(i))
             .sum::<u128>();
         let direction =
-            Direction::try_from(encoded.next().expect("direction digit")).expect("valid direction");
+            decode_direction(encoded.next().expect("direction digit")).expect("valid direction");
 
         (direction, distance)
     })))
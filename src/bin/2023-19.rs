@@ -3,7 +3,9 @@ use std::{
     collections::{HashMap, VecDeque},
 };
 
-advent_of_code::solution!(19);
+use advent_of_code::ranges::{Interval, IntervalSet};
+
+advent_of_code::solution!(2023, 19);
 
 #[derive(Clone, Copy)]
 enum Category {
@@ -171,12 +173,12 @@ pub fn part_two(input: &str) -> Option<u128> {
 
     struct Cursor {
         workflow: String,
-        bounds: [(u32, u32); 4],
+        bounds: [IntervalSet; 4],
     }
 
     let mut cursors = VecDeque::from_iter([Cursor {
         workflow: "in".to_string(),
-        bounds: [(1, 4000); 4],
+        bounds: std::array::from_fn(|_| Interval::inclusive(1, 4000).into()),
     }]);
 
     let mut final_count = 0;
@@ -186,7 +188,7 @@ pub fn part_two(input: &str) -> Option<u128> {
             .get(&cursor.workflow)
             .expect("workflow to be present");
 
-        workflow.into_iter().for_each(|condition| {
+        workflow.iter().for_each(|condition| {
             let (workflow, bounds) = match condition {
                 Condition::Branch {
                     category,
@@ -198,20 +200,17 @@ pub fn part_two(input: &str) -> Option<u128> {
                         let mut new_bounds = cursor.bounds.clone();
 
                         let i = *category as usize;
+                        let threshold = i64::from(*threshold);
 
-                        // Restrict bounds
+                        // Split bounds between the branch and the rest of the workflow
                         match comparision {
                             Ordering::Less => {
-                                // Restrict max
-                                new_bounds[i].1 = new_bounds[i].1.min(*threshold - 1);
-
-                                cursor.bounds[i].0 = new_bounds[i].1 + 1;
+                                (new_bounds[i], cursor.bounds[i]) =
+                                    cursor.bounds[i].split_at(threshold);
                             }
                             Ordering::Greater => {
-                                // Restrict min
-                                new_bounds[i].0 = new_bounds[i].0.max(*threshold + 1);
-
-                                cursor.bounds[i].1 = new_bounds[i].0 - 1;
+                                (cursor.bounds[i], new_bounds[i]) =
+                                    cursor.bounds[i].split_at(threshold + 1);
                             }
                             Ordering::Equal => {
                                 unreachable!("equals comparision shouldn't be present")
@@ -221,14 +220,14 @@ pub fn part_two(input: &str) -> Option<u128> {
                         new_bounds
                     })
                 }
-                Condition::Default(workflow) => (workflow, cursor.bounds),
+                Condition::Default(workflow) => (workflow, cursor.bounds.clone()),
             };
 
             match workflow {
                 Workflow::Accept => {
                     final_count += bounds
-                        .into_iter()
-                        .map(|(min, max)| (max - min + 1) as u128)
+                        .iter()
+                        .map(|bound| bound.len() as u128)
                         .product::<u128>();
                 }
                 Workflow::Custom(workflow) => cursors.push_back(Cursor {
@@ -1,6 +1,8 @@
 use std::collections::{HashMap, VecDeque};
 
-advent_of_code::solution!(20);
+use advent_of_code::math;
+
+advent_of_code::solution!(2023, 20);
 
 struct Module {
     ty: ModuleType,
@@ -249,10 +251,10 @@ pub fn part_two(input: &str) -> Option<u64> {
         .iter()
         .find(|(_, module)| module.connections.contains(&"rx".to_string()))
         .map(|(name, _)| name)
-        .cloned()
-        .unwrap();
+        .cloned()?;
 
-    let mut high_timings = vec![0; module_inputs.get(&target).unwrap().len()];
+    // The first two presses on which each of target's children sends a high pulse
+    let mut high_timings: Vec<Vec<u64>> = vec![vec![]; module_inputs.get(&target).unwrap().len()];
 
     loop {
         iterations += 1;
@@ -289,16 +291,12 @@ pub fn part_two(input: &str) -> Option<u64> {
                         .map(|(i, _)| i)
                         .unwrap();
 
-                    if connection == &target
-                        && matches!(output, Pulse::High)
-                        && high_timings[input_id] == 0
-                    {
-                        high_timings[input_id] = iterations;
-                    }
+                    if connection == &target && matches!(output, Pulse::High) {
+                        let timings = &mut high_timings[input_id];
 
-                    if high_timings.iter().all(|&timing| timing > 0) {
-                        // Find the LCM of all the timings
-                        return Some(high_timings.into_iter().fold(1u64, num::integer::lcm));
+                        if timings.len() < 2 && timings.last() != Some(&iterations) {
+                            timings.push(iterations);
+                        }
                     }
 
                     // Update input to reflect new output value
@@ -313,6 +311,15 @@ pub fn part_two(input: &str) -> Option<u64> {
                 }
             }
         }
+
+        if high_timings.iter().all(|timings| timings.len() == 2) {
+            // Each child sends a high pulse every so many presses, find when they all line up
+            return math::first_common_hit(
+                high_timings
+                    .iter()
+                    .map(|timings| (timings[0], timings[1] - timings[0])),
+            );
+        }
     }
 }
 
@@ -0,0 +1,163 @@
+//! Cycle detection for state machines that are stepped over and over, so the state after a huge
+//! number of steps can be found without taking all of them.
+//!
+//! [`find_cycle`] remembers every state it has seen, which is quick but needs the states to be
+//! hashed and kept around. [`find_cycle_brent`] only keeps a couple of states around, at the cost
+//! of taking more steps.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where the states of a state machine start repeating: the state after `start` steps is the
+/// first one that comes back, and it comes back every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The smallest number of steps that leads to the same state as `steps` steps.
+    pub fn reduce(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.length
+        }
+    }
+
+    /// The state after `steps` steps from `initial`, taking at most `start + length` steps.
+    pub fn state_after<S>(&self, initial: S, mut step: impl FnMut(&S) -> S, steps: usize) -> S {
+        (0..self.reduce(steps)).fold(initial, |state, _| step(&state))
+    }
+}
+
+/// Finds the cycle of the states reached from `initial`, remembering every state on the way.
+pub fn find_cycle<S: Clone + Hash + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut state = initial;
+
+    for steps in 0.. {
+        if let Some(start) = seen.insert(state.clone(), steps) {
+            return Cycle {
+                start,
+                length: steps - start,
+            };
+        }
+        state = step(&state);
+    }
+
+    unreachable!("states repeat before running out of steps")
+}
+
+/// Finds the cycle of the states reached from `initial` with Brent's algorithm, which only keeps
+/// two states around at a time.
+pub fn find_cycle_brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // find the length by moving the hare ahead in powers of two until it meets the tortoise.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // with the hare a whole cycle ahead, they meet where the cycle starts.
+    let mut tortoise = initial.clone();
+    let mut hare = (0..length).fold(initial, |state, _| step(&state));
+    let mut start = 0;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The state after `steps` steps from `initial`, skipping ahead once the states start repeating.
+pub fn state_after<S: Clone + Hash + Eq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    steps: usize,
+) -> S {
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = initial;
+
+    for taken in 0..steps {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: taken - start,
+            };
+            return history.swap_remove(cycle.reduce(steps));
+        }
+
+        seen.insert(state.clone(), taken);
+        history.push(state.clone());
+        state = step(&state);
+    }
+
+    state
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find_cycle, find_cycle_brent, state_after, Cycle};
+
+    /// Goes 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+    fn step(&n: &u32) -> u32 {
+        if n == 5 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn finds_cycles() {
+        let cycle = Cycle {
+            start: 3,
+            length: 3,
+        };
+
+        assert_eq!(find_cycle(0, step), cycle);
+        assert_eq!(find_cycle_brent(0, step), cycle);
+        assert_eq!(
+            find_cycle_brent(4, step),
+            Cycle {
+                start: 0,
+                length: 3
+            }
+        );
+        assert_eq!(
+            find_cycle(7, |&n| n),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn skips_ahead() {
+        assert_eq!(state_after(0, step, 2), 2);
+        assert_eq!(state_after(0, step, 6), 3);
+        assert_eq!(state_after(0, step, 1_000_000_000), 4);
+        assert_eq!(
+            find_cycle_brent(0, step).state_after(0, step, 1_000_000_000),
+            4
+        );
+    }
+}
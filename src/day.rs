@@ -114,6 +114,121 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// A set of days of advent, e.g. to run only some of the solutions.
+///
+/// # Parsing
+/// Days are separated by commas and can be ranges. Ranges without a start or end
+/// run from the 1st or up to the 25th.
+///
+/// ```
+/// # use advent_of_code::{Day, DaySet};
+/// let days: DaySet = "1-3,8,24-".parse().unwrap();
+/// let days: Vec<u8> = days.iter().map(Day::into_inner).collect();
+/// assert_eq!(days, [1, 2, 3, 8, 24, 25]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaySet(u32);
+
+impl DaySet {
+    /// Every day of advent.
+    pub fn all() -> Self {
+        all_days().collect()
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.0 & (1 << day.0) != 0
+    }
+
+    pub fn insert(&mut self, day: Day) {
+        self.0 |= 1 << day.0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The days in this set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = Day> {
+        let set = *self;
+        all_days().filter(move |day| set.contains(*day))
+    }
+}
+
+impl Default for DaySet {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl From<Day> for DaySet {
+    fn from(day: Day) -> Self {
+        Self(1 << day.0)
+    }
+}
+
+impl FromIterator<Day> for DaySet {
+    fn from_iter<T: IntoIterator<Item = Day>>(iter: T) -> Self {
+        let mut set = Self(0);
+        for day in iter {
+            set.insert(day);
+        }
+        set
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_bound = |bound: &str, default: Day| match bound.trim() {
+            "" => Ok(default),
+            bound => bound.parse::<Day>().map_err(|_| DaySetFromStrError),
+        };
+
+        let mut set = Self(0);
+
+        for item in s.split(',') {
+            let (start, end) = match item.split_once('-') {
+                Some((start, end)) => (parse_bound(start, Day(1))?, parse_bound(end, Day(25))?),
+                None => {
+                    let day = item.trim().parse::<Day>().map_err(|_| DaySetFromStrError)?;
+                    (day, day)
+                }
+            };
+
+            if start > end {
+                return Err(DaySetFromStrError);
+            }
+
+            for day in start.0..=end.0 {
+                set.insert(Day(day));
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub struct DaySetFromStrError;
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "expecting days between 1 and 25 separated by commas, or ranges like `1-5` and `12-`",
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -134,7 +249,7 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DaySet};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +282,26 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn parses_day_sets() {
+        let days = |s: &str| {
+            s.parse::<DaySet>()
+                .map(|set| set.iter().map(Day::into_inner).collect::<Vec<_>>())
+        };
+
+        assert_eq!(days("8").unwrap(), [8]);
+        assert_eq!(
+            days("1-5,8,12-").unwrap(),
+            [1, 2, 3, 4, 5, 8, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25]
+        );
+        assert_eq!(days("-3, 3").unwrap(), [1, 2, 3]);
+        assert_eq!("-".parse::<DaySet>().unwrap(), DaySet::all());
+        assert!(days("").is_err());
+        assert!(days("5-1").is_err());
+        assert!(days("0-3").is_err());
+        assert!(days("24,26").is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,441 @@
+//! A rectangular grid of cells, as found in most puzzle inputs, along with the points and
+//! directions used to move around in it.
+
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+/// One of the four directions on a grid. North is up, towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// The change in `(x, y)` when taking a step in this direction.
+    pub const fn offset(self) -> (isize, isize) {
+        match self {
+            Self::North => (0, -1),
+            Self::East => (1, 0),
+            Self::South => (0, 1),
+            Self::West => (-1, 0),
+        }
+    }
+
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    pub const fn reverse(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    /// Whether this direction is east or west.
+    pub const fn is_horizontal(self) -> bool {
+        matches!(self, Self::East | Self::West)
+    }
+}
+
+impl From<Direction> for (isize, isize) {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+/// The offsets of the eight cells around a cell, the four orthogonal ones before the diagonals.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (0, 1),
+    (1, 0),
+    (0, -1),
+    (-1, 0),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// The position of a cell, with `x` the column and `y` the row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Moves by `(dx, dy)`, returns [`None`] if that would go below zero.
+    /// Use [`Grid::offset`] to stay within a grid.
+    pub fn offset(self, (dx, dy): (isize, isize)) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    /// Takes a step in `direction`, returns [`None`] if that would go below zero.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        self.offset(direction.offset())
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A grid of cells stored row after row.
+///
+/// # Display
+/// A grid of displayable cells displays as one line of text per row, like the input it was parsed from.
+///
+/// ```
+/// # use advent_of_code::grid::{Grid, Point};
+/// let grid = Grid::parse("#..\n.#.\n", |c| c == '#').unwrap();
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert!(grid[Point::new(1, 1)]);
+/// assert_eq!(grid.map(|&wall| if wall { '#' } else { '.' }).to_string(), "#..\n.#.\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a grid with one row per line, mapping each character to a cell.
+    /// Every line needs to be as long as the first one.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, ParseGridError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+
+            let line_width = cells.len() - len;
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(ParseGridError {
+                        row: height,
+                        expected: width,
+                        found: line_width,
+                    })
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `point` is inside the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// Moves `point` by `(dx, dy)`, returns [`None`] if that leaves the grid.
+    pub fn offset(&self, point: Point, offset: (isize, isize)) -> Option<Point> {
+        point.offset(offset).filter(|&point| self.contains(point))
+    }
+
+    /// Takes a step from `point` in `direction`, returns [`None`] if that leaves the grid.
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        self.offset(point, direction.offset())
+    }
+
+    /// The cells north, east, south and west of `point` that are inside the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The cells around `point` that are inside the grid, including diagonals.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |offset| self.offset(point, offset))
+    }
+
+    /// Every point of the grid, row after row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new(i % width, i / width))
+    }
+
+    /// Every cell of the grid along with its position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row after row, that matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        let i = self.cells.iter().position(predicate)?;
+        Some(Point::new(i % self.width, i / self.width))
+    }
+
+    /// # Panics
+    /// If `y` is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside the grid");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` panics on a size of zero, which an empty grid would have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// # Panics
+    /// If `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Creates a grid of the same size by mapping each cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Flips the grid over its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Rotates the grid a quarter turn clockwise, so the first column becomes the first row.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flat_map(Iterator::rev).cloned().collect(),
+        }
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise, so the last column becomes the first row.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|x| self.column(x))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// An error which can be returned when parsing a [`Grid`] whose rows aren't all the same length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGridError {
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Error for ParseGridError {}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expecting row {} to be {} cells long like the first row, found {}",
+            self.row + 1,
+            self.expected,
+            self.found
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, Grid, ParseGridError, Point};
+
+    fn parse(input: &str) -> Grid<char> {
+        Grid::parse(input, |c| c).unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = parse("abc\ndef\n");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        assert_eq!(
+            Grid::parse("ab\nc\n", |c| c),
+            Err(ParseGridError {
+                row: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(parse("").rows().count(), 0);
+    }
+
+    #[test]
+    fn steps_within_bounds() {
+        let grid = parse("ab\ncd\n");
+        let corner = Point::new(1, 0);
+
+        assert_eq!(grid.step(corner, Direction::South), Some(Point::new(1, 1)));
+        assert_eq!(grid.step(corner, Direction::North), None);
+        assert_eq!(grid.step(corner, Direction::East), None);
+        assert_eq!(
+            grid.neighbours(corner).collect::<Vec<_>>(),
+            [Point::new(1, 1), Point::new(0, 0)]
+        );
+        assert_eq!(grid.neighbours8(corner).count(), 3);
+
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = parse("abc\ndef\n");
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+}
@@ -1,4 +1,13 @@
+pub mod cycle;
 mod day;
+pub mod grid;
+pub mod math;
+mod puzzle;
+pub mod ranges;
+pub mod search;
 pub mod template;
+mod year;
 
 pub use day::*;
+pub use puzzle::*;
+pub use year::*;
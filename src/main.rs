@@ -1,55 +1,123 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, bench_compare, download, examples, read, scaffold,
+    solve::{self, Selection},
+    verify, watch,
+};
 use args::{parse, AppArguments};
 
+/// Every solution in `src/bin`, compiled into this binary. See `build.rs`.
+#[cfg(not(test))]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
-    use std::process;
+    use std::{process, time::Duration};
 
-    use advent_of_code::Day;
+    use advent_of_code::{
+        template::{bench::BenchConfig, commands::all::Timeouts, runner::RunOptions},
+        DaySet, PuzzleId, Year,
+    };
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
+        },
+        Examples {
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Solve {
-            day: Day,
+            year: Year,
+            days: DaySet,
             release: bool,
-            time: bool,
             submit: Option<u8>,
+            part: Option<u8>,
+            input: Option<String>,
+            watch: bool,
+            options: RunOptions,
         },
         All {
+            year: Year,
+            days: DaySet,
             release: bool,
-            time: bool,
+            options: RunOptions,
+            timeouts: Timeouts,
+            jobs: usize,
+        },
+        Verify {
+            year: Year,
+            release: bool,
+            record: bool,
+        },
+        BenchCompare {
+            year: Year,
+            baseline: Option<String>,
+            threshold: f64,
         },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        let year = args
+            .opt_value_from_str("--year")?
+            .unwrap_or_else(Year::current);
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year,
                 release: args.contains("--release"),
-                time: args.contains("--time"),
+                options: parse_run_options(&mut args)?,
+                timeouts: Timeouts {
+                    part: args
+                        .opt_value_from_str("--timeout")?
+                        .map(Duration::from_millis),
+                    day: args
+                        .opt_value_from_str("--day-timeout")?
+                        .map(Duration::from_millis),
+                },
+                jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+                // free arguments come last, once every option is taken.
+                days: args.opt_free_from_str()?.unwrap_or_default(),
+            },
+            Some("bench-compare") => AppArguments::BenchCompare {
+                year,
+                baseline: args.opt_value_from_str("--baseline")?,
+                threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: PuzzleId::new(year, args.free_from_str()?),
+            },
+            Some("examples") => AppArguments::Examples {
+                puzzle: PuzzleId::new(year, args.free_from_str()?),
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: PuzzleId::new(year, args.free_from_str()?),
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: PuzzleId::new(year, args.free_from_str()?),
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                year,
+                days: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                part: args.opt_value_from_str("--part")?,
+                input: args.opt_value_from_str("--input")?,
+                watch: args.contains("--watch"),
+                options: parse_run_options(&mut args)?,
+            },
+            Some("verify") => AppArguments::Verify {
+                year,
+                release: args.contains("--release"),
+                record: args.contains("--record"),
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -68,25 +136,84 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Parses how solutions are run and reported. Parts are only benched if `--time` is set.
+    fn parse_run_options(args: &mut pico_args::Arguments) -> Result<RunOptions, pico_args::Error> {
+        let is_timed = args.contains("--time");
+        let mut config = BenchConfig::default();
+
+        if let Some(millis) = args.opt_value_from_str("--bench-warmup")? {
+            config.warmup = Duration::from_millis(millis);
+        }
+
+        if let Some(millis) = args.opt_value_from_str("--bench-time")? {
+            config.budget = Duration::from_millis(millis);
+        }
+
+        config.iterations = args.opt_value_from_str("--bench-iterations")?;
+
+        Ok(RunOptions {
+            bench: is_timed.then_some(config),
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            track_memory: args.contains("--mem"),
+        })
+    }
 }
 
 fn main() {
+    #[cfg(not(test))]
+    advent_of_code::template::registry::register(solutions::SOLUTIONS);
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                year,
+                days,
+                release,
+                options,
+                timeouts,
+                jobs,
+            } => all::handle(year, &days, release, &options, timeouts, jobs),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Examples { puzzle } => examples::handle(puzzle),
+            AppArguments::Scaffold { puzzle } => scaffold::handle(puzzle),
             AppArguments::Solve {
-                day,
+                year,
+                days,
                 release,
-                time,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                part,
+                input,
+                watch,
+                options,
+            } => {
+                let selection = Selection {
+                    submit,
+                    part,
+                    input,
+                };
+
+                if watch {
+                    watch::handle(year, &days, release, &selection, &options);
+                } else {
+                    solve::handle(year, &days, release, selection, &options);
+                }
+            }
+            AppArguments::Verify {
+                year,
+                release,
+                record,
+            } => verify::handle(year, release, record),
+            AppArguments::BenchCompare {
+                year,
+                baseline,
+                threshold,
+            } => bench_compare::handle(year, baseline.as_deref(), threshold),
         },
     };
 }
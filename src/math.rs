@@ -0,0 +1,114 @@
+//! Number theory for puzzles about things that repeat: greatest common divisors and least common
+//! multiples of many numbers, and solving simultaneous congruences to find when cycles line up.
+
+use num::Integer;
+
+/// The greatest common divisor of all `values`, or zero if there are none.
+pub fn gcd<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values
+        .into_iter()
+        .fold(T::zero(), |acc, value| acc.gcd(&value))
+}
+
+/// The least common multiple of all `values`, or one if there are none.
+pub fn lcm<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values
+        .into_iter()
+        .fold(T::one(), |acc, value| acc.lcm(&value))
+}
+
+/// Solves the congruences `x ≡ residue (mod modulus)` for every pair in `congruences`, which need
+/// not be coprime. Returns the solution as `(residue, modulus)` where `residue` is the smallest
+/// non-negative solution and `modulus` the least common multiple of the moduli, or [`None`] if
+/// the congruences contradict each other. Moduli must be positive.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences.into_iter().try_fold(
+        (0, 1),
+        |(residue, modulus), (other_residue, other_modulus)| {
+            assert!(other_modulus > 0, "moduli must be positive");
+
+            let extended = modulus.extended_gcd(&other_modulus);
+            let gcd = extended.gcd;
+            let difference = other_residue - residue;
+
+            if difference % gcd != 0 {
+                return None;
+            }
+
+            // `extended.x` is the inverse of `modulus / gcd` modulo `other_modulus / gcd`.
+            let step = other_modulus / gcd;
+            let k = (difference / gcd % step * (extended.x % step)).rem_euclid(step);
+            let lcm = modulus * step;
+
+            Some(((residue + modulus * k).rem_euclid(lcm), lcm))
+        },
+    )
+}
+
+/// Finds the first time at which every cycle hits, where a cycle given as `(offset, period)`
+/// hits at `offset`, `offset + period`, `offset + 2 * period` and so on. A period of zero hits at
+/// `offset` only. Returns [`None`] if the cycles never hit at the same time.
+pub fn first_common_hit(cycles: impl IntoIterator<Item = (u64, u64)>) -> Option<u64> {
+    let cycles: Vec<(u64, u64)> = cycles.into_iter().collect();
+    let earliest = cycles.iter().map(|&(offset, _)| offset).max()?;
+
+    let hits = |time: u64| {
+        cycles.iter().all(|&(offset, period)| match period {
+            0 => time == offset,
+            period => time >= offset && (time - offset).is_multiple_of(period),
+        })
+    };
+
+    // a cycle that hits only once leaves only one time to check.
+    if let Some(&(offset, _)) = cycles.iter().find(|&&(_, period)| period == 0) {
+        return hits(offset).then_some(offset);
+    }
+
+    let (residue, modulus) = crt(cycles
+        .iter()
+        .map(|&(offset, period)| (i128::from(offset), i128::from(period))))?;
+
+    // the smallest solution may come before some cycles have started.
+    let earliest = i128::from(earliest);
+    let time = if residue >= earliest {
+        residue
+    } else {
+        residue + Integer::div_ceil(&(earliest - residue), &modulus) * modulus
+    };
+
+    u64::try_from(time).ok()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{crt, first_common_hit, gcd, lcm};
+
+    #[test]
+    fn folds_gcd_and_lcm() {
+        assert_eq!(gcd([12u64, 18, 30]), 6);
+        assert_eq!(lcm([4u64, 6, 10]), 60);
+        assert_eq!(lcm(Vec::<u64>::new()), 1);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // moduli that share a factor
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+    }
+
+    #[test]
+    fn finds_first_common_hit() {
+        // cycles that start at zero line up at their least common multiple.
+        assert_eq!(first_common_hit([(3, 3), (4, 4)]), Some(12));
+        // cycles with offsets
+        assert_eq!(first_common_hit([(2, 4), (4, 6)]), Some(10));
+        assert_eq!(first_common_hit([(22, 4), (4, 6)]), Some(22));
+        assert_eq!(first_common_hit([(7, 0), (1, 3)]), Some(7));
+        assert_eq!(first_common_hit([(1, 2), (2, 2)]), None);
+        assert_eq!(first_common_hit([]), None);
+    }
+}
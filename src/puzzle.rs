@@ -0,0 +1,79 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{Day, Year};
+
+/// Identifies a single puzzle by its year and day.
+///
+/// # Display
+/// This value displays as `{year}-{day}`, which is also the name of the puzzle's solution binary.
+///
+/// ```
+/// # use advent_of_code::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2023).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2023-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('-').ok_or(PuzzleIdFromStrError)?;
+        Ok(Self {
+            year: year.parse().map_err(|_| PuzzleIdFromStrError)?,
+            day: day.parse().map_err(|_| PuzzleIdFromStrError)?,
+        })
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError;
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a puzzle in the format `{year}-{day}`, e.g. `2023-08`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::PuzzleId;
+    use crate::{day, year};
+
+    #[test]
+    fn round_trips_through_strings() {
+        let puzzle = PuzzleId::new(year!(2023), day!(8));
+        assert_eq!(puzzle.to_string(), "2023-08");
+        assert_eq!("2023-08".parse::<PuzzleId>().unwrap(), puzzle);
+        assert_eq!("2023-8".parse::<PuzzleId>().unwrap(), puzzle);
+        assert!("2023".parse::<PuzzleId>().is_err());
+        assert!("2023-26".parse::<PuzzleId>().is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
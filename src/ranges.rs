@@ -0,0 +1,354 @@
+//! Sets of integers stored as ranges, for puzzles that track huge ranges of values through
+//! transformations instead of the values one by one.
+
+use std::fmt::Display;
+
+/// The integers from `start` up to but not including `end`. Empty if `end <= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// The interval from `start` to `end`, both included.
+    pub const fn inclusive(start: i64, end: i64) -> Self {
+        Self::new(start, end + 1)
+    }
+
+    /// Every integer, as far as intervals are concerned.
+    pub const fn all() -> Self {
+        Self::new(i64::MIN, i64::MAX)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// How many integers are in the interval.
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start)
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Moves the interval by `offset`. The bounds of [`Interval::all`] stay put, as they stand in
+    /// for infinity.
+    pub fn shift(&self, offset: i64) -> Self {
+        let shift = |bound: i64| match bound {
+            i64::MIN | i64::MAX => bound,
+            bound => bound + offset,
+        };
+        Self::new(shift(self.start), shift(self.end))
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A set of integers, stored as sorted intervals that don't overlap or touch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The intervals of the set, in order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // merge with the intervals it overlaps or touches.
+        let first = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end);
+
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, other| {
+                Interval::new(merged.start.min(other.start), merged.end.max(other.end))
+            });
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for &interval in &other.intervals {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .flat_map(|interval| {
+                other
+                    .intervals
+                    .iter()
+                    .map(|other| interval.intersection(other))
+            })
+            .collect()
+    }
+
+    /// The integers of this set that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    /// Every integer that isn't in the set.
+    pub fn complement(&self) -> Self {
+        let mut start = i64::MIN;
+        let mut complement = Self::new();
+
+        for interval in &self.intervals {
+            complement.insert(Interval::new(start, interval.start));
+            start = interval.end;
+        }
+        complement.insert(Interval::new(start, i64::MAX));
+
+        complement
+    }
+
+    /// Splits the set into the integers below `threshold` and the ones from `threshold` on.
+    pub fn split_at(&self, threshold: i64) -> (Self, Self) {
+        (
+            self.intersection(&Interval::new(i64::MIN, threshold).into()),
+            self.intersection(&Interval::new(threshold, i64::MAX).into()),
+        )
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A function that adds a different offset to the integers of different intervals, and leaves
+/// the integers outside of them unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PiecewiseMap {
+    /// Sorted pieces that cover every integer, along with the offset they add.
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl Default for PiecewiseMap {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl PiecewiseMap {
+    /// The map that leaves every integer unchanged.
+    pub fn identity() -> Self {
+        Self {
+            pieces: vec![(Interval::all(), 0)],
+        }
+    }
+
+    /// Adds `offset` to the integers of `source`, instead of what the map did to them before.
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        if source.is_empty() {
+            return;
+        }
+
+        let outside = IntervalSet::from(source).complement();
+
+        let mut pieces: Vec<(Interval, i64)> = self
+            .pieces
+            .iter()
+            .flat_map(|&(piece, piece_offset)| {
+                IntervalSet::from(piece)
+                    .intersection(&outside)
+                    .intervals
+                    .into_iter()
+                    .map(move |piece| (piece, piece_offset))
+            })
+            .chain([(source, offset)])
+            .collect();
+
+        pieces.sort_unstable();
+        self.pieces = pieces;
+    }
+
+    /// The pieces that don't leave integers unchanged, in order.
+    pub fn pieces(&self) -> impl Iterator<Item = (Interval, i64)> + '_ {
+        self.pieces
+            .iter()
+            .copied()
+            .filter(|&(_, offset)| offset != 0)
+    }
+
+    pub fn apply(&self, value: i64) -> i64 {
+        self.pieces
+            .iter()
+            .find(|(piece, _)| piece.contains(value))
+            .map_or(value, |(_, offset)| value + offset)
+    }
+
+    /// The integers that the integers of `set` map to.
+    pub fn apply_set(&self, set: &IntervalSet) -> IntervalSet {
+        self.pieces
+            .iter()
+            .flat_map(|&(piece, offset)| {
+                set.intersection(&piece.into())
+                    .intervals
+                    .into_iter()
+                    .map(move |interval| interval.shift(offset))
+            })
+            .collect()
+    }
+
+    /// The map that applies this map and then `next`.
+    pub fn then(&self, next: &Self) -> Self {
+        let mut pieces: Vec<(Interval, i64)> = self
+            .pieces
+            .iter()
+            .flat_map(|&(piece, offset)| {
+                let image = piece.shift(offset);
+
+                next.pieces
+                    .iter()
+                    .filter_map(move |&(next_piece, next_offset)| {
+                        let overlap = image.intersection(&next_piece);
+                        (!overlap.is_empty())
+                            .then(|| (overlap.shift(-offset), offset + next_offset))
+                    })
+            })
+            .collect();
+
+        pieces.sort_unstable();
+
+        // merge neighbouring pieces that add the same offset.
+        pieces.dedup_by(|piece, previous| {
+            let touches = previous.0.end == piece.0.start && previous.1 == piece.1;
+            if touches {
+                previous.0.end = piece.0.end;
+            }
+            touches
+        });
+
+        Self { pieces }
+    }
+}
+
+impl FromIterator<(Interval, i64)> for PiecewiseMap {
+    fn from_iter<T: IntoIterator<Item = (Interval, i64)>>(iter: T) -> Self {
+        let mut map = Self::identity();
+        for (source, offset) in iter {
+            map.insert(source, offset);
+        }
+        map
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Interval, IntervalSet, PiecewiseMap};
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn combines_interval_sets() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12)]);
+
+        assert_eq!(set(&[(0, 3), (3, 5), (7, 7)]), set(&[(0, 5)]));
+        assert_eq!(a.union(&b), set(&[(0, 15)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 12)]));
+        assert_eq!(a.difference(&b), set(&[(0, 3), (12, 15)]));
+        assert_eq!(a.len(), 10);
+        assert_eq!(a.split_at(12), (set(&[(0, 5), (10, 12)]), set(&[(12, 15)])));
+    }
+
+    #[test]
+    fn composes_piecewise_maps() {
+        let first: PiecewiseMap = [(Interval::new(0, 10), 100)].into_iter().collect();
+        let second: PiecewiseMap = [(Interval::new(105, 200), -105), (Interval::new(0, 5), 1)]
+            .into_iter()
+            .collect();
+        let both = first.then(&second);
+
+        for value in -5..20 {
+            assert_eq!(both.apply(value), second.apply(first.apply(value)));
+        }
+
+        assert_eq!(
+            both.apply_set(&set(&[(0, 20)])),
+            set(&[(0, 5), (10, 20), (100, 105)])
+        );
+    }
+}
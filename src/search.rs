@@ -0,0 +1,326 @@
+//! Graph searches over any state that can be hashed: breadth-first search for unweighted graphs,
+//! and Dijkstra and A* for weighted ones. The graph is given by a closure listing the successors of a state.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A cost that can be summed along a path, e.g. `u32` or `usize`. `Default` is the cost of an empty path.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// Controls what a search keeps track of besides the cost of reaching the goal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SearchConfig {
+    /// Whether to reconstruct the path to the goal.
+    pub track_path: bool,
+}
+
+/// How much work a search did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SearchStats {
+    /// States taken off the queue and expanded, including the goal.
+    pub visited: usize,
+    /// States put on the queue, including the starts. States may be queued more than once in
+    /// Dijkstra and A*, when a cheaper way to reach them is found.
+    pub queued: usize,
+}
+
+/// The goal a search reached and how it got there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub goal: S,
+    pub cost: C,
+    /// The states from a start to the goal, both included. Only set if [`SearchConfig::track_path`] is.
+    pub path: Option<Vec<S>>,
+    pub stats: SearchStats,
+}
+
+/// Every state reached so far, along with the state it was reached from.
+struct Arena<S> {
+    nodes: Vec<(S, Option<usize>)>,
+}
+
+impl<S: Clone> Arena<S> {
+    fn push(&mut self, state: S, parent: Option<usize>) -> usize {
+        self.nodes.push((state, parent));
+        self.nodes.len() - 1
+    }
+
+    fn path(&self, mut index: usize) -> Vec<S> {
+        let mut path = vec![self.nodes[index].0.clone()];
+        while let Some(parent) = self.nodes[index].1 {
+            path.push(self.nodes[parent].0.clone());
+            index = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    fn found<C>(
+        &self,
+        index: usize,
+        cost: C,
+        config: SearchConfig,
+        stats: SearchStats,
+    ) -> Found<S, C> {
+        Found {
+            goal: self.nodes[index].0.clone(),
+            cost,
+            path: config.track_path.then(|| self.path(index)),
+            stats,
+        }
+    }
+}
+
+/// Finds the goal that takes the fewest steps to reach from any of `starts`, where each step
+/// goes from a state to one of its `successors`.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    config: SearchConfig,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+{
+    let mut arena = Arena { nodes: vec![] };
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    let mut stats = SearchStats::default();
+
+    for start in starts {
+        if seen.insert(start.clone()) {
+            queue.push_back((arena.push(start, None), 0));
+            stats.queued += 1;
+        }
+    }
+
+    while let Some((index, steps)) = queue.pop_front() {
+        stats.visited += 1;
+
+        if is_goal(&arena.nodes[index].0) {
+            return Some(arena.found(index, steps, config, stats));
+        }
+
+        for next in successors(&arena.nodes[index].0) {
+            if seen.insert(next.clone()) {
+                queue.push_back((arena.push(next, Some(index)), steps + 1));
+                stats.queued += 1;
+            }
+        }
+    }
+
+    None
+}
+
+/// Every state that can be reached from any of `starts`, along with the fewest steps it takes
+/// to reach it. This is a flood fill of the graph.
+pub fn reachable<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+{
+    let mut steps = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !steps.contains_key(&start) {
+            steps.insert(start.clone(), 0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, distance)) = queue.pop_front() {
+        for next in successors(&state) {
+            if !steps.contains_key(&next) {
+                steps.insert(next.clone(), distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    steps
+}
+
+/// Finds the cheapest goal to reach from `starts`, each given with the cost of starting there.
+/// `successors` lists the states a state leads to along with the cost of that step.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = (S, C)>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+    config: SearchConfig,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal, config)
+}
+
+/// Like [`dijkstra`], but expands the states that look closest to a goal first. The `heuristic`
+/// estimates the cost left to reach a goal from a state, and must never overestimate it for the
+/// result to be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = (S, C)>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    config: SearchConfig,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut frontier = Frontier {
+        arena: Arena { nodes: vec![] },
+        best: HashMap::new(),
+        queue: BinaryHeap::new(),
+        stats: SearchStats::default(),
+    };
+
+    for (start, cost) in starts {
+        if frontier
+            .best
+            .get(&start)
+            .is_some_and(|&(best, _)| best <= cost)
+        {
+            continue;
+        }
+
+        let estimate = cost + heuristic(&start);
+        frontier.push(start, cost, estimate, None);
+    }
+
+    while let Some(Reverse((_, cost, index))) = frontier.queue.pop() {
+        let state = frontier.arena.nodes[index].0.clone();
+
+        // a cheaper way to this state was queued after this one.
+        if frontier.best[&state].1 != index {
+            continue;
+        }
+
+        frontier.stats.visited += 1;
+
+        if is_goal(&state) {
+            return Some(frontier.arena.found(index, cost, config, frontier.stats));
+        }
+
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if frontier
+                .best
+                .get(&next)
+                .is_some_and(|&(best, _)| best <= cost)
+            {
+                continue;
+            }
+
+            let estimate = cost + heuristic(&next);
+            frontier.push(next, cost, estimate, Some(index));
+        }
+    }
+
+    None
+}
+
+/// The states a weighted search has reached and has yet to expand.
+struct Frontier<S, C> {
+    arena: Arena<S>,
+    /// The cheapest known cost of each state, and the node it was reached with.
+    best: HashMap<S, (C, usize)>,
+    /// Nodes to expand, cheapest estimate first.
+    queue: BinaryHeap<Reverse<(C, C, usize)>>,
+    stats: SearchStats,
+}
+
+impl<S: Clone + Hash + Eq, C: Cost> Frontier<S, C> {
+    fn push(&mut self, state: S, cost: C, estimate: C, parent: Option<usize>) {
+        let index = self.arena.push(state.clone(), parent);
+        self.best.insert(state, (cost, index));
+        self.queue.push(Reverse((estimate, cost, index)));
+        self.stats.queued += 1;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, dijkstra, reachable, SearchConfig};
+
+    const TRACK_PATH: SearchConfig = SearchConfig { track_path: true };
+
+    /// A line of numbers up to 10 where each one leads to the next, and even ones also skip ahead
+    /// by 3 at a higher cost.
+    fn successors(&n: &u32) -> Vec<(u32, u32)> {
+        if n >= 10 {
+            return vec![];
+        }
+
+        let mut next = vec![(n + 1, 1)];
+        if n % 2 == 0 {
+            next.push((n + 3, 5));
+        }
+        next
+    }
+
+    #[test]
+    fn finds_fewest_steps() {
+        let found = bfs(
+            [0],
+            |n| successors(n).into_iter().map(|(n, _)| n),
+            |&n| n == 7,
+            TRACK_PATH,
+        )
+        .unwrap();
+
+        assert_eq!(found.cost, 3);
+        assert_eq!(found.path.unwrap(), [0, 3, 4, 7]);
+        assert!(found.stats.visited <= found.stats.queued);
+
+        let steps = reachable([0], |&n| (n < 4).then_some(n + 1));
+        assert_eq!(steps.len(), 5);
+        assert_eq!(steps[&4], 4);
+    }
+
+    #[test]
+    fn finds_cheapest_path() {
+        let found = dijkstra([(0, 0)], successors, |&n| n == 6, TRACK_PATH).unwrap();
+
+        assert_eq!(found.cost, 6);
+        assert_eq!(found.path.unwrap(), [0, 1, 2, 3, 4, 5, 6]);
+
+        let guided = astar(
+            [(0, 0)],
+            successors,
+            |&n| 6u32.saturating_sub(n),
+            |&n| n == 6,
+            TRACK_PATH,
+        )
+        .unwrap();
+        assert_eq!(guided.cost, 6);
+        assert!(guided.stats.visited <= found.stats.visited);
+
+        // the cheaper copy of a start given twice wins, whichever comes first.
+        for starts in [[(0, 0), (0, 5)], [(0, 5), (0, 0)]] {
+            let found = dijkstra(starts, successors, |&n| n == 6, TRACK_PATH).unwrap();
+            assert_eq!(found.cost, 6);
+        }
+
+        let without_path = dijkstra([(0, 0)], successors, |&n| n == 6, SearchConfig::default());
+        assert_eq!(without_path.unwrap().path, None);
+        assert_eq!(
+            dijkstra([(7, 0)], successors, |&n| n == 6, TRACK_PATH),
+            None
+        );
+    }
+}
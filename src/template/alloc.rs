@@ -0,0 +1,184 @@
+/// Opt-in allocation tracking. Building with the `alloc_tracking` feature installs [`CountingAllocator`]
+/// as the global allocator, so the runner can report what each part allocates.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    fs,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use serde::Serialize;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator and counts allocations, allocated bytes and live bytes.
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc_tracking")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// What a step allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// The most memory that was live at once, on top of what was live before the step.
+    pub peak_bytes: usize,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs · {} allocated · {} peak",
+            self.allocations,
+            format_bytes(self.bytes as u64),
+            format_bytes(self.peak_bytes as u64)
+        )
+    }
+}
+
+/// Whether this build counts allocations.
+#[must_use]
+pub const fn is_enabled() -> bool {
+    cfg!(feature = "alloc_tracking")
+}
+
+/// Runs `func` and returns what it allocated. Only meaningful if [`is_enabled`],
+/// and while no other thread is allocating.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, AllocStats) {
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+
+    (result, stats)
+}
+
+/// The peak resident set size of this process in bytes, if the platform reports it.
+#[must_use]
+pub fn peak_rss() -> Option<u64> {
+    parse_peak_rss(&fs::read_to_string("/proc/self/status").ok()?)
+}
+
+fn parse_peak_rss(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kilobytes: u64 = line
+        .trim_start_matches("VmHWM:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::alloc::{GlobalAlloc, Layout};
+
+    use super::{format_bytes, measure, parse_peak_rss, CountingAllocator};
+
+    #[test]
+    fn counts_allocations() {
+        let layout = Layout::from_size_align(4096, 8).unwrap();
+
+        let (_, stats) = measure(|| unsafe {
+            let a = CountingAllocator.alloc(layout);
+            let b = CountingAllocator.alloc(layout);
+            CountingAllocator.dealloc(a, layout);
+            CountingAllocator.dealloc(b, layout);
+        });
+
+        // other tests may allocate concurrently if the allocator is installed.
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 8192);
+        assert!(stats.peak_bytes >= 8192);
+    }
+
+    #[test]
+    fn reads_peak_rss() {
+        let status = "Name:\tadvent_of_code\nVmPeak:\t  20000 kB\nVmHWM:\t    3072 kB\n";
+        assert_eq!(parse_peak_rss(status), Some(3 * 1024 * 1024));
+        assert_eq!(parse_peak_rss("Name:\tfoo\n"), None);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
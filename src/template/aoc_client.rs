@@ -0,0 +1,274 @@
+/// Native client for the Advent of Code website.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::html;
+use crate::PuzzleId;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/andogq/aoc23 (advent_of_code template client)";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    RequestFailed(String),
+    BadStatus(u16),
+    UnexpectedResponse,
+    IoError,
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "session cookie not found. Set AOC_SESSION or write it to ~/.adventofcode.session."
+            ),
+            AocClientError::RequestFailed(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::UnexpectedResponse => {
+                write!(f, "could not understand the server's response.")
+            }
+            AocClientError::IoError => write!(f, "could not write output files to file system."),
+        }
+    }
+}
+
+/// The verdict returned by the server after submitting an answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// An answer was submitted too recently. Contains the remaining wait time, if given.
+    TooRecent(Option<String>),
+    /// The part has already been solved, or is not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "right answer"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "wrong answer"),
+            Verdict::TooRecent(Some(wait)) => write!(f, "please wait {wait}"),
+            Verdict::TooRecent(None) => write!(f, "please wait"),
+            Verdict::WrongLevel => write!(f, "already solved or locked"),
+            Verdict::Unknown => write!(f, "unknown response"),
+        }
+    }
+}
+
+/// Checks that a session cookie is available.
+pub fn check() -> Result<(), AocClientError> {
+    get_session().map(|_| ())
+}
+
+pub fn read(puzzle: PuzzleId) -> Result<String, AocClientError> {
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    let description = fetch_puzzle(puzzle)?;
+    write_file(&puzzle_path, &description)?;
+
+    println!("{description}");
+    Ok(description)
+}
+
+pub fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    let input = get(&format!("{}/input", day_url(puzzle)))?;
+    let description = fetch_puzzle(puzzle)?;
+
+    write_file(&input_path, &input)?;
+    write_file(&puzzle_path, &description)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+pub fn submit(puzzle: PuzzleId, part: u8, result: &str) -> Result<Verdict, AocClientError> {
+    let response = agent()
+        .post(&format!("{}/answer", day_url(puzzle)))
+        .set("Cookie", &format!("session={}", get_session()?))
+        .send_form(&[("level", &part.to_string()), ("answer", result)])
+        .map_err(map_request_error)?
+        .into_string()
+        .map_err(|e| AocClientError::RequestFailed(e.to_string()))?;
+
+    let message = html::articles(&response)
+        .first()
+        .map(|article| html::to_text(article))
+        .ok_or(AocClientError::UnexpectedResponse)?;
+
+    println!("{message}");
+    Ok(parse_verdict(&message))
+}
+
+/// Determines the [`Verdict`] from the text of a submission response.
+#[must_use]
+pub fn parse_verdict(message: &str) -> Verdict {
+    if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else if message.contains("You gave an answer too recently") {
+        let wait = message
+            .split("You have ")
+            .nth(1)
+            .and_then(|rest| rest.split(" left to wait").next())
+            .map(ToString::to_string);
+        Verdict::TooRecent(wait)
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown
+    }
+}
+
+fn fetch_puzzle(puzzle: PuzzleId) -> Result<String, AocClientError> {
+    let page = get(&day_url(puzzle))?;
+    let puzzle = html::articles_to_markdown(&page);
+
+    if puzzle.is_empty() {
+        return Err(AocClientError::UnexpectedResponse);
+    }
+
+    Ok(puzzle)
+}
+
+fn get(url: &str) -> Result<String, AocClientError> {
+    agent()
+        .get(url)
+        .set("Cookie", &format!("session={}", get_session()?))
+        .call()
+        .map_err(map_request_error)?
+        .into_string()
+        .map_err(|e| AocClientError::RequestFailed(e.to_string()))
+}
+
+fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .redirects(0)
+        .build()
+}
+
+fn map_request_error(e: ureq::Error) -> AocClientError {
+    match e {
+        ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+        ureq::Error::Transport(e) => AocClientError::RequestFailed(e.to_string()),
+    }
+}
+
+fn day_url(puzzle: PuzzleId) -> String {
+    format!(
+        "{}/{}/day/{}",
+        get_base_url(),
+        puzzle.year,
+        puzzle.day.into_inner()
+    )
+}
+
+/// The server to talk to. Can be overridden with `AOC_BASE_URL`, e.g. to point at a local stand-in.
+fn get_base_url() -> String {
+    env::var("AOC_BASE_URL")
+        .map(|url| url.trim_end_matches('/').to_string())
+        .unwrap_or_else(|_| DEFAULT_BASE_URL.into())
+}
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to `~/.adventofcode.session`.
+fn get_session() -> Result<String, AocClientError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".adventofcode.session"))
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or(AocClientError::SessionNotFound)
+}
+
+/// Writes `contents` to `path`, creating the parent directories as needed.
+fn write_file(path: &str, contents: &str) -> Result<(), AocClientError> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent).map_err(|_| AocClientError::IoError)?;
+    }
+
+    fs::write(path, contents).map_err(|_| AocClientError::IoError)
+}
+
+fn get_input_path(puzzle: PuzzleId) -> String {
+    format!("data/{}/inputs/{}.txt", puzzle.year, puzzle.day)
+}
+
+/// Where the description of a puzzle is saved by [`download`] and [`read`].
+#[must_use]
+pub fn get_puzzle_path(puzzle: PuzzleId) -> String {
+    format!("data/{}/puzzles/{}.md", puzzle.year, puzzle.day)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_verdict, Verdict};
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            parse_verdict("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_verdict("That's not the right answer; your answer is too high. Please wait one minute before trying again."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            parse_verdict("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            parse_verdict("That's not the right answer. If you're stuck, make sure you're using the full input data."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            parse_verdict("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 4m 12s left to wait."),
+            Verdict::TooRecent(Some("4m 12s".into()))
+        );
+        assert_eq!(
+            parse_verdict(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            Verdict::WrongLevel
+        );
+        assert_eq!(parse_verdict("Something else"), Verdict::Unknown);
+    }
+}
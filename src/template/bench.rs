@@ -0,0 +1,210 @@
+/// Benchmarking of solution parts: a warmup phase followed by timed samples, summarized as statistics.
+use std::{
+    cmp,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use serde::{Serialize, Serializer};
+
+/// Controls how long a part is benched for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// How long to run the part before taking samples.
+    pub warmup: Duration,
+    /// Approximate time to spend taking samples. Ignored if `iterations` is set.
+    pub budget: Duration,
+    /// A fixed number of samples to take.
+    pub iterations: Option<u128>,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(100),
+            budget: Duration::from_secs(1),
+            iterations: None,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Number of samples to take for a function that runs in about `base_time`.
+    /// Without a fixed iteration count, this fills the budget with at least 10 and at most 10000 samples.
+    #[must_use]
+    pub fn iterations_for(&self, base_time: Duration) -> u128 {
+        self.iterations.unwrap_or_else(|| {
+            (self.budget.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000)
+        })
+    }
+}
+
+/// Summary statistics of a benchmark. Durations serialize as nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct BenchStats {
+    pub samples: u128,
+    #[serde(serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(serialize_with = "as_nanos")]
+    pub max: Duration,
+    #[serde(serialize_with = "as_nanos")]
+    pub mean: Duration,
+    #[serde(serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(serialize_with = "as_nanos")]
+    pub stddev: Duration,
+    #[serde(serialize_with = "as_nanos")]
+    pub p95: Duration,
+    #[serde(serialize_with = "as_nanos")]
+    pub p99: Duration,
+    /// Samples outside of Tukey's fences (1.5 times the interquartile range beyond the quartiles).
+    pub outliers: usize,
+}
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+impl BenchStats {
+    /// Computes statistics over `samples`. Returns `None` if there are no samples.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let mut nanos: Vec<f64> = samples.iter().map(|x| x.as_nanos() as f64).collect();
+        nanos.sort_by(f64::total_cmp);
+
+        #[allow(clippy::cast_precision_loss)]
+        let len = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (len - 1.0)
+        } else {
+            0.0
+        };
+
+        let q1 = quantile(&nanos, 0.25);
+        let q3 = quantile(&nanos, 0.75);
+        let fence = 1.5 * (q3 - q1);
+        let outliers = nanos
+            .iter()
+            .filter(|&&x| x < q1 - fence || x > q3 + fence)
+            .count();
+
+        Some(Self {
+            samples: samples.len() as u128,
+            min: from_nanos(nanos[0]),
+            max: from_nanos(nanos[nanos.len() - 1]),
+            mean: from_nanos(mean),
+            median: from_nanos(quantile(&nanos, 0.5)),
+            stddev: from_nanos(variance.sqrt()),
+            p95: from_nanos(quantile(&nanos, 0.95)),
+            p99: from_nanos(quantile(&nanos, 0.99)),
+            outliers,
+        })
+    }
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.1?} · mean {:.1?} · p95 {:.1?} · p99 {:.1?} · max {:.1?}",
+            self.min, self.mean, self.p95, self.p99, self.max
+        )
+    }
+}
+
+/// Linearly interpolated quantile `q` of sorted `values`.
+fn quantile(values: &[f64], q: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = q * (values.len() - 1) as f64;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+
+    values[lower] + (values[upper] - values[lower]) * (rank - rank.floor())
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/// Benches `func`: runs it for the warmup period, then samples it according to `config`.
+pub fn run<I: Clone, T>(func: impl Fn(I) -> T, input: &I, config: &BenchConfig) -> BenchStats {
+    let mut warmup_timers: Vec<Duration> = vec![];
+    let warmup = Instant::now();
+
+    while warmup_timers.is_empty() || warmup.elapsed() < config.warmup {
+        let cloned = input.clone();
+        let timer = Instant::now();
+        func(cloned);
+        warmup_timers.push(timer.elapsed());
+    }
+
+    warmup_timers.sort();
+    let base_time = warmup_timers[warmup_timers.len() / 2];
+
+    let mut timers: Vec<Duration> = vec![];
+
+    for _ in 0..config.iterations_for(base_time) {
+        // need a clone here to make the borrow checker happy.
+        let cloned = input.clone();
+        let timer = Instant::now();
+        func(cloned);
+        timers.push(timer.elapsed());
+    }
+
+    BenchStats::from_samples(&timers).expect("at least one sample was taken")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{BenchConfig, BenchStats};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn computes_summary_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[50, 10, 40, 20, 30])).unwrap();
+
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.stddev, Duration::from_nanos(16));
+        assert_eq!(stats.p95, Duration::from_nanos(48));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn flags_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 500])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.median, Duration::from_nanos(11));
+        assert!(BenchStats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn derives_iterations_from_budget() {
+        let config = BenchConfig::default();
+        assert_eq!(config.iterations_for(Duration::from_millis(1)), 1000);
+        assert_eq!(config.iterations_for(Duration::from_secs(1)), 10);
+        assert_eq!(config.iterations_for(Duration::ZERO), 10000);
+
+        let fixed = BenchConfig {
+            iterations: Some(3),
+            ..config
+        };
+        assert_eq!(fixed.iterations_for(Duration::from_millis(1)), 3);
+    }
+}
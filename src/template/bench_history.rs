@@ -0,0 +1,336 @@
+/// Keeps a history of timed runs in `data/benchmarks.csv`, so runs can be compared to spot regressions.
+/// Every row is the benchmark of one part. Rows that share a timestamp belong to the same run.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::bench::BenchStats;
+use crate::year::civil_from_days;
+use crate::{Day, PuzzleId, Year};
+
+const HEADER: &str = "timestamp,commit,year,day,part,samples,min_ns,max_ns,mean_ns,median_ns,stddev_ns,p95_ns,p99_ns,outliers";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse benchmark history: {e}"),
+            Error::IO(e) => write!(f, "could not access benchmark history: {e}"),
+        }
+    }
+}
+
+/// The benchmark of a single part in a run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub timestamp: String,
+    pub commit: String,
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub stats: BenchStats,
+}
+
+impl Entry {
+    fn to_line(&self) -> String {
+        let stats = &self.stats;
+
+        format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.commit,
+            self.puzzle.year,
+            self.puzzle.day.into_inner(),
+            self.part,
+            stats.samples,
+            stats.min.as_nanos(),
+            stats.max.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.median.as_nanos(),
+            stats.stddev.as_nanos(),
+            stats.p95.as_nanos(),
+            stats.p99.as_nanos(),
+            stats.outliers,
+        )
+    }
+
+    fn parse_line(line: &str) -> Result<Self, Error> {
+        let fields: Vec<&str> = line.split(',').collect();
+
+        let [timestamp, commit, year, day, part, samples, min, max, mean, median, stddev, p95, p99, outliers] =
+            fields[..]
+        else {
+            return Err(Error::Parser(format!(
+                "unexpected number of columns: {line}"
+            )));
+        };
+
+        let invalid = |field: &str| Error::Parser(format!("invalid {field}: {line}"));
+        let nanos = |value: &str| {
+            value
+                .parse()
+                .map(Duration::from_nanos)
+                .map_err(|_| invalid("duration"))
+        };
+
+        Ok(Self {
+            timestamp: timestamp.into(),
+            commit: commit.into(),
+            puzzle: PuzzleId::new(
+                year.parse::<Year>().map_err(|_| invalid("year"))?,
+                day.parse::<Day>().map_err(|_| invalid("day"))?,
+            ),
+            part: part.parse().map_err(|_| invalid("part"))?,
+            stats: BenchStats {
+                samples: samples.parse().map_err(|_| invalid("samples"))?,
+                min: nanos(min)?,
+                max: nanos(max)?,
+                mean: nanos(mean)?,
+                median: nanos(median)?,
+                stddev: nanos(stddev)?,
+                p95: nanos(p95)?,
+                p99: nanos(p99)?,
+                outliers: outliers.parse().map_err(|_| invalid("outliers"))?,
+            },
+        })
+    }
+}
+
+/// All entries of a single timed run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub timestamp: String,
+    pub commit: String,
+    pub entries: Vec<Entry>,
+}
+
+impl Run {
+    fn get(&self, puzzle: PuzzleId, part: u8) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.puzzle == puzzle && entry.part == part)
+    }
+}
+
+/// How the median of a part changed between two runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// The change relative to the baseline, in percent. Positive means slower.
+    pub change: f64,
+}
+
+impl Comparison {
+    #[must_use]
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change > threshold
+    }
+}
+
+fn get_path() -> &'static Path {
+    Path::new("data/benchmarks.csv")
+}
+
+/// Appends `entries` to the history, creating the file if it doesn't exist yet.
+pub fn append(entries: &[Entry]) -> Result<(), Error> {
+    let path = get_path();
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if is_new {
+        writeln!(file, "{HEADER}")?;
+    }
+
+    for entry in entries {
+        writeln!(file, "{}", entry.to_line())?;
+    }
+
+    Ok(())
+}
+
+/// Loads every entry in the history. A missing history has no entries.
+pub fn load() -> Result<Vec<Entry>, Error> {
+    match fs::read_to_string(get_path()) {
+        Ok(contents) => parse(&contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn parse(contents: &str) -> Result<Vec<Entry>, Error> {
+    contents
+        .lines()
+        .filter(|line| !line.is_empty() && *line != HEADER)
+        .map(Entry::parse_line)
+        .collect()
+}
+
+/// Groups entries into runs, in the order they were recorded.
+#[must_use]
+pub fn runs(entries: Vec<Entry>) -> Vec<Run> {
+    let mut runs: Vec<Run> = vec![];
+
+    for entry in entries {
+        match runs.last_mut() {
+            Some(run) if run.timestamp == entry.timestamp => run.entries.push(entry),
+            _ => runs.push(Run {
+                timestamp: entry.timestamp.clone(),
+                commit: entry.commit.clone(),
+                entries: vec![entry],
+            }),
+        }
+    }
+
+    runs
+}
+
+/// Compares every part of `current` that was also benched in `baseline`.
+#[must_use]
+pub fn compare(baseline: &Run, current: &Run) -> Vec<Comparison> {
+    current
+        .entries
+        .iter()
+        .filter_map(|entry| {
+            let previous = baseline.get(entry.puzzle, entry.part)?;
+
+            #[allow(clippy::cast_precision_loss)]
+            let (before, after) = (
+                previous.stats.median.as_nanos() as f64,
+                entry.stats.median.as_nanos() as f64,
+            );
+
+            Some(Comparison {
+                puzzle: entry.puzzle,
+                part: entry.part,
+                baseline: previous.stats.median,
+                current: entry.stats.median,
+                change: (after - before) / before.max(1.0) * 100.0,
+            })
+        })
+        .collect()
+}
+
+/// The abbreviated hash of `rev` (e.g. `HEAD` or a tag), if git can resolve it.
+#[must_use]
+pub fn resolve_commit(rev: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", &format!("{rev}^{{commit}}")])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Formats `time` as an ISO 8601 timestamp in UTC, e.g. `2023-12-01T05:00:00Z`.
+#[must_use]
+pub fn format_timestamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+
+    #[allow(clippy::cast_possible_wrap)]
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let secs_of_day = secs % 86_400;
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{compare, format_timestamp, parse, runs, Entry, HEADER};
+    use crate::template::bench::BenchStats;
+    use crate::{day, year, PuzzleId};
+
+    fn entry(timestamp: &str, day: u8, part: u8, median: u64) -> Entry {
+        let median = Duration::from_nanos(median);
+
+        Entry {
+            timestamp: timestamp.into(),
+            commit: "abc1234".into(),
+            puzzle: PuzzleId::new(year!(2023), crate::Day::new(day).unwrap()),
+            part,
+            stats: BenchStats {
+                samples: 100,
+                min: median,
+                max: median,
+                mean: median,
+                median,
+                stddev: Duration::ZERO,
+                p95: median,
+                p99: median,
+                outliers: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn round_trips_entries() {
+        let entries = vec![entry("t1", 1, 1, 1000), entry("t1", 1, 2, 2000)];
+        let lines: Vec<String> = entries.iter().map(Entry::to_line).collect();
+        let contents = format!("{HEADER}\n{}\n", lines.join("\n"));
+
+        assert_eq!(parse(&contents).unwrap(), entries);
+        assert!(parse("t1,abc1234,2023,1").is_err());
+    }
+
+    #[test]
+    fn flags_regressions_against_baseline() {
+        let history = runs(vec![
+            entry("t1", 1, 1, 1000),
+            entry("t1", 1, 2, 2000),
+            entry("t2", 1, 1, 1050),
+            entry("t2", 1, 2, 3000),
+            entry("t2", 2, 1, 500),
+        ]);
+
+        assert_eq!(history.len(), 2);
+
+        let comparisons = compare(&history[0], &history[1]);
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].puzzle.day, day!(1));
+        assert!(!comparisons[0].is_regression(10.0));
+        assert!(comparisons[1].is_regression(10.0));
+        assert!((comparisons[1].change - 50.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_701_406_861);
+        assert_eq!(format_timestamp(time), "2023-12-01T05:01:01Z");
+    }
+}
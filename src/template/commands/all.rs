@@ -1,254 +1,630 @@
-use std::io;
+use std::{
+    collections::BTreeMap,
+    io::{stdout, Write},
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError, Sender},
+    },
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
 
 use crate::template::{
-    readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    alloc,
+    bench_history::{self, Entry},
+    ledger::{self, Ledger},
+    panics::Panic,
+    readme_benchmarks::{self, PartTiming, Timings},
+    registry::{self, Answer, Solution},
+    runner::{
+        report_parse, report_part, report_progress, report_timeout, time_parse, time_part,
+        OutputFormat, PartRecord, PartResult, PartStatus, RunOptions, Step, Timing,
+    },
+    try_read_file, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{Day, DaySet, PuzzleId, Year};
+
+/// How long a solution may run before `all` gives up on it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timeouts {
+    /// The limit for each step, i.e. parsing or a single part.
+    pub part: Option<Duration>,
+    /// The limit for all steps of a day together.
+    pub day: Option<Duration>,
+}
+
+/// How a day went, from best to worst. A day takes the worst outcome of its parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Outcome {
+    Solved,
+    /// There is no solution or input yet, or a part returned `None`.
+    Unsolved,
+    /// An answer differs from the expected answer in the day's ledger.
+    Failed,
+    Panicked,
+    TimedOut,
+}
+
+/// What the thread running a day reports back.
+enum Progress {
+    /// A step finished its first run, and is benched next if timed.
+    FirstRun(Step, Option<Answer>),
+    Parsed(Result<Timing, Panic>),
+    Solved(u8, PartResult),
+}
+
+/// The results of a day, as far as it got.
+struct DayRun {
+    parse: Option<Timing>,
+    parts: Vec<(u8, PartResult)>,
+    parse_panic: Option<Panic>,
+    timed_out: Option<(Step, Duration)>,
+    /// Whether the day was timed or had its memory tracked, which stops after a day times out.
+    measured: bool,
+}
+
+pub fn handle(
+    year: Year,
+    days: &DaySet,
+    is_release: bool,
+    options: &RunOptions,
+    timeouts: Timeouts,
+    jobs: usize,
+) {
+    if let Some(code) = child_commands::rerun_in_profile(is_release, options.track_memory) {
+        process::exit(code);
+    }
 
-pub fn handle(is_release: bool, is_timed: bool) {
     let mut timings: Vec<Timings> = vec![];
+    let mut history: Vec<Entry> = vec![];
+    let mut outcomes: Vec<Outcome> = vec![];
+    let mut problems: Vec<String> = vec![];
+    // a day that timed out keeps running and skews the peak RSS from then on.
+    let mut is_peak_rss_reliable = true;
+    let timestamp = bench_history::format_timestamp(SystemTime::now());
+    let commit = bench_history::resolve_commit("HEAD").unwrap_or_else(|| "unknown".into());
+
+    let is_timed = options.bench.is_some();
+    let is_text = options.format == OutputFormat::Text;
+
+    // days running side by side skew each other's timings and allocation counts.
+    let jobs = if jobs > 1 && (is_timed || options.track_memory) {
+        eprintln!("Warning: --time and --mem run one day at a time, ignoring --jobs.");
+        1
+    } else {
+        jobs.max(1)
+    };
+
+    for (day, run) in days
+        .iter()
+        .zip(run_days(year, days, options, timeouts, jobs))
+    {
+        let puzzle = PuzzleId::new(year, day);
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+        let Some(run) = run else {
+            outcomes.push(Outcome::Unsolved);
+            continue;
+        };
+
+        let is_timed = is_timed && run.measured;
+        let mut outcome = Outcome::Solved;
+        let mut timing = Timings {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            total_nanos: 0_f64,
+        };
+
+        if let Some(panic) = &run.parse_panic {
+            outcome = Outcome::Panicked;
+            problems.push(format!("Day {day} parse: {panic}"));
         }
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+        if let (Some(parse_timing), true) = (&run.parse, is_timed) {
+            timing.parse = Some(to_part_timing(parse_timing));
+            timing.total_nanos += to_nanos(parse_timing);
+        }
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        let ledger = Ledger::load(puzzle).unwrap_or_else(|e| {
+            eprintln!("{}: {e}", ledger::get_path(puzzle));
+            Ledger::default()
+        });
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+        for (part, result) in &run.parts {
+            let part = *part;
+
+            let part_outcome = match (&result.panic, &result.answer) {
+                (Some(panic), _) => {
+                    problems.push(format!("Day {day} part {part}: {panic}"));
+                    Outcome::Panicked
+                }
+                (None, None) => Outcome::Unsolved,
+                (None, Some(answer)) => match ledger.part(part).expected() {
+                    Some(expected) if expected.trim() != answer.to_string().trim() => {
+                        problems.push(format!(
+                            "Day {day} part {part}: expected {expected}, got {answer}"
+                        ));
+                        Outcome::Failed
+                    }
+                    _ => Outcome::Solved,
+                },
+            };
+            outcome = outcome.max(part_outcome);
+
+            if is_timed && result.answer.is_some() {
+                let timing_str = Some(to_part_timing(&result.timing));
+
+                if part == 1 {
+                    timing.part_1 = timing_str;
+                } else {
+                    timing.part_2 = timing_str;
+                }
+
+                timing.total_nanos += to_nanos(&result.timing);
+
+                if let Some(stats) = result.timing.stats {
+                    history.push(Entry {
+                        timestamp: timestamp.clone(),
+                        commit: commit.clone(),
+                        puzzle,
+                        part,
+                        stats,
+                    });
+                }
+            }
         }
-    });
+
+        if let Some((step, limit)) = run.timed_out {
+            is_peak_rss_reliable &= !run.measured;
+            outcome = Outcome::TimedOut;
+            problems.push(format!(
+                "Day {day} {}: timed out after {limit:.1?}",
+                step.to_string().to_lowercase()
+            ));
+        }
+
+        outcomes.push(outcome);
+
+        // days that weren't timed keep their row in the README.
+        if is_timed {
+            timings.push(timing);
+        }
+    }
+
+    let count = |outcome: Outcome| outcomes.iter().filter(|x| **x == outcome).count();
+
+    if is_text {
+        println!("\n{ANSI_BOLD}Summary:{ANSI_RESET}");
+        println!(
+            "  {} solved · {} unsolved · {} failed · {} panicked · {} timed out",
+            count(Outcome::Solved),
+            count(Outcome::Unsolved),
+            count(Outcome::Failed),
+            count(Outcome::Panicked),
+            count(Outcome::TimedOut),
+        );
+
+        for problem in &problems {
+            println!("  ✖ {problem}");
+        }
+    }
+
+    let peak_rss = (options.track_memory && is_peak_rss_reliable)
+        .then(alloc::peak_rss)
+        .flatten()
+        .map(alloc::format_bytes);
+
+    if is_text {
+        if let Some(peak_rss) = &peak_rss {
+            println!("\n{ANSI_BOLD}Peak RSS:{ANSI_RESET} {ANSI_ITALIC}{peak_rss}{ANSI_RESET}");
+        }
+    }
 
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if is_text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
 
         if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
+            match readme_benchmarks::update(year, timings, total_millis, peak_rss.as_deref()) {
+                Ok(()) if is_text => println!("Successfully updated README with benchmarks."),
+                Ok(()) => {}
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
                 }
             }
+
+            match bench_history::append(&history) {
+                Ok(()) if is_text => println!("Appended benchmarks to history."),
+                Ok(()) => {}
+                Err(e) => eprintln!("{e}"),
+            }
         }
     }
-}
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
+    if outcomes.iter().any(|outcome| *outcome > Outcome::Unsolved) {
+        // also ends the threads of steps that timed out.
+        process::exit(1);
+    }
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+/// Runs `days` of `year`, `jobs` days at a time, and returns the results in day order.
+/// Output is printed in day order too: with more than one job, a day's output is buffered until
+/// the days before it are done.
+fn run_days(
+    year: Year,
+    days: &DaySet,
+    options: &RunOptions,
+    timeouts: Timeouts,
+    jobs: usize,
+) -> Vec<Option<DayRun>> {
+    let days: Vec<Day> = days.iter().collect();
+
+    if jobs == 1 {
+        let mut options = options.clone();
+
+        return days
+            .into_iter()
+            .enumerate()
+            .map(|(i, day)| {
+                let puzzle = PuzzleId::new(year, day);
+                let run = run_day(puzzle, i == 0, &options, timeouts, &mut stdout());
+
+                // the day's solver keeps running in the background, competing with the days
+                // after it for CPU and adding its allocations to their counts.
+                let is_measured = options.bench.is_some() || options.track_memory;
+                if is_measured && run.as_ref().is_some_and(|run| run.timed_out.is_some()) {
+                    eprintln!(
+                        "Warning: day {day} is still running in the background, not timing or tracking memory of the remaining days."
+                    );
+                    options.bench = None;
+                    options.track_memory = false;
+                }
+
+                run
+            })
+            .collect();
     }
-}
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    let mut runs = Vec::with_capacity(days.len());
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let (days, next, sender) = (&days, &next, sender.clone());
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(i) else {
+                    break;
+                };
+
+                let mut out = vec![];
+                let puzzle = PuzzleId::new(year, day);
+                let run = run_day(puzzle, i == 0, options, timeouts, &mut out);
+                let _ = sender.send((day, out, run));
+            });
         }
+        drop(sender);
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let mut finished = BTreeMap::new();
 
-        if is_release {
-            args.push("--release");
-        }
+        for (day, out, run) in receiver {
+            finished.insert(day, (out, run));
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            while let Some((out, run)) = days.get(runs.len()).and_then(|day| finished.remove(day)) {
+                let _ = stdout().write_all(&out);
+                runs.push(run);
+            }
         }
+    });
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
-
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+    runs
+}
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+/// Runs a day and writes its results to `out`. Returns nothing if there is no solution or input to run.
+fn run_day(
+    puzzle: PuzzleId,
+    is_first: bool,
+    options: &RunOptions,
+    timeouts: Timeouts,
+    out: &mut impl Write,
+) -> Option<DayRun> {
+    let day = puzzle.day;
+    let is_text = options.format == OutputFormat::Text;
+
+    if is_text {
+        if !is_first {
+            let _ = writeln!(out);
+        }
 
-        let mut output = vec![];
+        let _ = writeln!(out, "{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        let _ = writeln!(out, "------");
+    }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+    let Some(solution) = registry::get(puzzle) else {
+        if is_text {
+            let _ = writeln!(out, "Not solved.");
+        }
+        return None;
+    };
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+    let Ok(input) = try_read_file("inputs", puzzle) else {
+        if is_text {
+            let _ = writeln!(out, "Missing input.");
+        } else {
+            report_not_run(out, puzzle, 1, PartStatus::MissingInput, options);
         }
+        return None;
+    };
 
-        thread.join().unwrap();
-        cmd.wait()?;
+    Some(watch_day(puzzle, *solution, input, options, timeouts, out))
+}
 
-        Ok(output)
-    }
+/// Runs a day on its own thread and reports its steps as they finish, giving up once a step or the whole
+/// day runs out of time. Threads can't be killed, so a step that timed out is left running in the
+/// background until `all` exits, and the day's remaining steps are skipped. See [`run_days`] for how
+/// the days after it are run.
+fn watch_day(
+    puzzle: PuzzleId,
+    solution: Solution,
+    input: String,
+    options: &RunOptions,
+    timeouts: Timeouts,
+    out: &mut impl Write,
+) -> DayRun {
+    let (sender, receiver) = mpsc::channel();
+    let thread_options = options.clone();
+    thread::spawn(move || run_steps(solution, input, &thread_options, &sender));
+
+    let mut run = DayRun {
+        parse: None,
+        parts: vec![],
+        parse_panic: None,
+        timed_out: None,
+        measured: options.bench.is_some() || options.track_memory,
+    };
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+    let day_deadline = timeouts.day.map(|limit| (Instant::now() + limit, limit));
+    let step_deadline = || timeouts.part.map(|limit| (Instant::now() + limit, limit));
+
+    let mut step = if solution.parse.is_some() {
+        Step::Parse
+    } else {
+        Step::Part(1)
+    };
+    let mut deadline = earliest(step_deadline(), day_deadline);
+
+    loop {
+        let progress = match deadline {
+            Some((deadline, _)) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        match progress {
+            Ok(Progress::FirstRun(step, answer)) => {
+                let _ = report_progress(out, step, answer.as_ref(), options);
+            }
+            Ok(Progress::Parsed(Ok(timing))) => {
+                let _ = report_parse(out, puzzle, Ok(&timing), options);
+                run.parse = Some(timing);
+                step = Step::Part(1);
+                deadline = earliest(step_deadline(), day_deadline);
+            }
+            Ok(Progress::Parsed(Err(panic))) => {
+                let _ = report_parse(out, puzzle, Err(&panic), options);
+                report_not_run(out, puzzle, 1, PartStatus::Panicked, options);
+                run.parse_panic = Some(panic);
+            }
+            Ok(Progress::Solved(part, result)) => {
+                let _ = report_part(out, puzzle, part, &result, options);
+                run.parts.push((part, result));
+                step = Step::Part(part + 1);
+                deadline = earliest(step_deadline(), day_deadline);
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let (_, limit) = deadline.expect("only times out with a deadline");
+                let _ = report_timeout(out, puzzle, step, limit, options);
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
+                let next_part = match step {
+                    Step::Parse => 1,
+                    Step::Part(part) => part + 1,
                 };
+                report_not_run(out, puzzle, next_part, PartStatus::TimedOut, options);
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+                run.timed_out = Some((step, limit));
+                break;
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
 
-                timings.total_nanos += nanos;
-            });
+    run
+}
 
-        timings
-    }
+/// Runs the steps of a day, sending their results to `progress`.
+fn run_steps(solution: Solution, input: String, options: &RunOptions, progress: &Sender<Progress>) {
+    // sending only fails once the day was given up on, so there's no one left to tell.
+    let send = |message| {
+        let _ = progress.send(message);
+    };
+
+    let input = match solution.parse {
+        Some(parse) => match time_parse(parse, &input, options, || {
+            send(Progress::FirstRun(Step::Parse, None));
+        }) {
+            Ok((parsed, timing)) => {
+                send(Progress::Parsed(Ok(timing)));
+                parsed
+            }
+            Err(panic) => {
+                send(Progress::Parsed(Err(panic)));
+                return;
+            }
+        },
+        None => solution.prepare(input),
+    };
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    for part in 1..=2 {
+        let result = time_part(solution.part(part), input.as_ref(), options, |answer| {
+            send(Progress::FirstRun(Step::Part(part), answer.clone()));
+        });
+        send(Progress::Solved(part, result));
     }
+}
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+/// In JSON, every part gets a record, including the parts from `first_part` on that never ran.
+fn report_not_run(
+    out: &mut impl Write,
+    puzzle: PuzzleId,
+    first_part: u8,
+    status: PartStatus,
+    options: &RunOptions,
+) {
+    if options.format == OutputFormat::Json {
+        for part in first_part..=2 {
+            let _ = PartRecord::not_run(puzzle, part, status).write_to(out);
+        }
+    }
+}
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+/// The deadline that comes first, along with the limit it enforces.
+fn earliest(
+    a: Option<(Instant, Duration)>,
+    b: Option<(Instant, Duration)>,
+) -> Option<(Instant, Duration)> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(if a.0 <= b.0 { a } else { b }),
+        (a, b) => a.or(b),
+    }
+}
 
-        Some((str_timing, parsed_timing))
+fn to_part_timing(timing: &Timing) -> PartTiming {
+    PartTiming {
+        median: format!("{:.1?}", timing.duration),
+        spread: timing.stats.map(|stats| format!("{:.1?}", stats.stddev)),
+        memory: timing
+            .memory
+            .map(|memory| alloc::format_bytes(memory.peak_bytes as u64)),
     }
+}
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+#[allow(clippy::cast_precision_loss)]
+fn to_nanos(timing: &Timing) -> f64 {
+    timing.duration.as_nanos() as f64
+}
+
+/// Solutions are compiled into the main binary and run in-process.
+/// This module takes care of re-running a command when the binary was built with the wrong profile or features.
+pub(crate) mod child_commands {
+    use std::{
+        env,
+        path::{Path, PathBuf},
+        process::{Command, ExitStatus},
+    };
+
+    use crate::template::alloc;
+
+    /// Where builds with the `alloc_tracking` feature go, inside the target directory. Keeping them
+    /// apart means they don't replace the binary that is running, nor make the next regular build
+    /// start over.
+    const ALLOC_TRACKING_DIR: &str = "alloc_tracking";
+
+    /// Solutions run with the profile this binary was built with. If that doesn't match the requested
+    /// profile, or memory should be tracked without the `alloc_tracking` feature, builds a matching binary
+    /// once, re-runs the current command with it and returns its exit code.
+    ///
+    /// As solutions run in-process through the registry, this builds the `advent_of_code` binary
+    /// rather than every day binary, and there is nothing left to run with `target/{profile}/NN`.
+    /// Builds with `alloc_tracking` go to `target/alloc_tracking/{profile}`.
+    pub fn rerun_in_profile(is_release: bool, track_memory: bool) -> Option<i32> {
+        let needs_alloc_tracking = track_memory && !alloc::is_enabled();
+
+        if is_release == cfg!(not(debug_assertions)) && !needs_alloc_tracking {
+            return None;
+        }
+
+        let profile = if is_release { "release" } else { "debug" };
+
+        let Some(target_dir) = target_dir(track_memory) else {
+            eprintln!("Could not find the target directory of advent_of_code.");
+            return Some(1);
+        };
+
+        match build(&target_dir, is_release, track_memory) {
+            Ok(status) if status.success() => {}
+            Ok(status) => {
+                eprintln!(
+                    "Failed to build the solutions in the {profile} profile, see the errors above."
+                );
+                return Some(status.code().unwrap_or(1));
+            }
+            Err(e) => {
+                eprintln!("Failed to run cargo: {e}");
+                return Some(1);
+            }
+        }
+
+        let binary = target_dir
+            .join(profile)
+            .join(format!("advent_of_code{}", env::consts::EXE_SUFFIX));
+
+        let status = Command::new(&binary).args(env::args().skip(1)).status();
+
+        Some(match status {
+            Ok(status) => status.code().unwrap_or(1),
+            Err(e) => {
+                eprintln!("Failed to run {}: {e}", binary.display());
+                1
+            }
+        })
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
+    fn build(
+        target_dir: &Path,
+        is_release: bool,
+        track_memory: bool,
+    ) -> std::io::Result<ExitStatus> {
+        let mut args = vec!["build", "--quiet", "--bin", "advent_of_code"];
 
-        use crate::day;
+        if is_release {
+            args.push("--release");
+        }
 
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
-
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
-
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+        if track_memory {
+            args.extend(["--features", "alloc_tracking"]);
         }
+
+        Command::new("cargo")
+            .args(&args)
+            .arg("--target-dir")
+            .arg(target_dir)
+            .status()
+    }
+
+    /// The target directory to build into: the one of the binary that is running, or the
+    /// directory for builds with `alloc_tracking` inside it.
+    fn target_dir(track_memory: bool) -> Option<PathBuf> {
+        let current = env::current_exe().ok()?;
+        let mut target_dir = current.parent()?.parent()?;
+
+        if target_dir.ends_with(ALLOC_TRACKING_DIR) {
+            target_dir = target_dir.parent()?;
+        }
+
+        Some(if track_memory {
+            target_dir.join(ALLOC_TRACKING_DIR)
+        } else {
+            target_dir.to_path_buf()
+        })
     }
 }
@@ -0,0 +1,93 @@
+use std::process;
+
+use crate::template::{
+    bench_history::{self, Run},
+    ANSI_BOLD, ANSI_RESET,
+};
+use crate::Year;
+
+/// Compares the latest timed run of `year` to the run before it, or to the most recent run
+/// recorded at the `baseline` commit, and flags parts that got slower by more than `threshold` percent.
+pub fn handle(year: Year, baseline: Option<&str>, threshold: f64) {
+    let entries = match bench_history::load() {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let mut runs = bench_history::runs(
+        entries
+            .into_iter()
+            .filter(|entry| entry.puzzle.year == year)
+            .collect(),
+    );
+
+    let Some(current) = runs.pop() else {
+        eprintln!("No benchmarks recorded for {year}. Run `cargo time --year {year}` first.");
+        process::exit(1);
+    };
+
+    let Some(previous) = find_baseline(&runs, baseline) else {
+        match baseline {
+            Some(baseline) => eprintln!("No earlier run found for baseline \"{baseline}\"."),
+            None => eprintln!("Only one run recorded for {year}, nothing to compare against."),
+        }
+        process::exit(1);
+    };
+
+    println!(
+        "Comparing {} ({}) to {} ({}).",
+        current.timestamp, current.commit, previous.timestamp, previous.commit
+    );
+    println!();
+    println!("{ANSI_BOLD}Day | Part | Baseline | Current | Change{ANSI_RESET}");
+
+    let comparisons = bench_history::compare(previous, &current);
+
+    for comparison in &comparisons {
+        let flag = if comparison.is_regression(threshold) {
+            " ✖"
+        } else {
+            ""
+        };
+
+        println!(
+            " {} |  {}   | {:.1?} | {:.1?} | {:+.1}%{flag}",
+            comparison.puzzle.day,
+            comparison.part,
+            comparison.baseline,
+            comparison.current,
+            comparison.change
+        );
+    }
+
+    let regressions = comparisons
+        .iter()
+        .filter(|comparison| comparison.is_regression(threshold))
+        .count();
+
+    println!();
+    println!(
+        "{ANSI_BOLD}{regressions} of {} parts slower by more than {threshold}%.{ANSI_RESET}",
+        comparisons.len()
+    );
+
+    if regressions > 0 {
+        process::exit(1);
+    }
+}
+
+/// The run to compare against: the latest run whose commit matches `baseline`, or the latest run otherwise.
+fn find_baseline<'a>(runs: &'a [Run], baseline: Option<&str>) -> Option<&'a Run> {
+    let Some(baseline) = baseline else {
+        return runs.last();
+    };
+
+    let commit = bench_history::resolve_commit(baseline).unwrap_or_else(|| baseline.into());
+
+    runs.iter()
+        .rev()
+        .find(|run| run.commit.starts_with(&commit) || commit.starts_with(&run.commit))
+}
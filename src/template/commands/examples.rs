@@ -0,0 +1,87 @@
+use std::{fs, io, process};
+
+use crate::template::{aoc_client, example_paths, examples};
+use crate::PuzzleId;
+
+/// The tests of a freshly scaffolded solution, which are filled in with the answers found.
+const PLACEHOLDERS: [(&str, &str); 2] = [
+    (
+        "part_one",
+        r#"assert_examples(DAY, part_one, &[("", None)]);"#,
+    ),
+    (
+        "part_two",
+        r#"assert_examples(DAY, part_two, &[("", None)]);"#,
+    ),
+];
+
+pub fn handle(puzzle: PuzzleId) {
+    let puzzle_path = aoc_client::get_puzzle_path(puzzle);
+
+    let description = match fs::read_to_string(&puzzle_path) {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("Could not read \"{puzzle_path}\", download the puzzle first: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = update(puzzle, &description) {
+        eprintln!("Failed to write examples: {e}");
+        process::exit(1);
+    }
+}
+
+/// Writes the examples found in the description of a puzzle to its examples directory, and fills in their
+/// answers in the tests of its solution that are still placeholders. Examples that were already edited are kept.
+pub(crate) fn update(puzzle: PuzzleId, description: &str) -> io::Result<()> {
+    let found = examples::extract(description);
+
+    if found.examples.is_empty() {
+        println!("Could not find any examples in the puzzle description.");
+        return Ok(());
+    }
+
+    for example in &found.examples {
+        let path = &example_paths(puzzle, &example.name)[0];
+
+        match fs::read_to_string(path) {
+            Ok(existing) if existing.trim() == example.input.trim() => {}
+            Ok(existing) if !existing.trim().is_empty() => {
+                println!(
+                    "Kept \"{}\", it differs from the example in the puzzle.",
+                    path.display()
+                );
+            }
+            _ => {
+                if let Some(dir) = path.parent() {
+                    fs::create_dir_all(dir)?;
+                }
+                fs::write(path, &example.input)?;
+                println!("Wrote example to \"{}\".", path.display());
+            }
+        }
+    }
+
+    let module_path = format!("src/bin/{puzzle}.rs");
+    let Ok(module) = fs::read_to_string(&module_path) else {
+        return Ok(());
+    };
+
+    let mut updated = module.clone();
+    for ((part_fn, placeholder), part) in PLACEHOLDERS.iter().zip(1..) {
+        if let Some(table) = found.test_table(part) {
+            updated = updated.replace(
+                placeholder,
+                &format!("assert_examples(DAY, {part_fn}, {table});"),
+            );
+        }
+    }
+
+    if updated != module {
+        fs::write(&module_path, updated)?;
+        println!("Filled in the expected answers of the examples in \"{module_path}\".");
+    }
+
+    Ok(())
+}
@@ -1,5 +1,9 @@
 pub mod all;
+pub mod bench_compare;
 pub mod download;
+pub mod examples;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod verify;
+pub mod watch;
@@ -1,12 +1,13 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
 
-use crate::Day;
+use crate::template::{aoc_client, commands::examples};
+use crate::PuzzleId;
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
 
 pub fn part_one(input: &str) -> Option<u32> {
     None
@@ -19,17 +20,19 @@ pub fn part_two(input: &str) -> Option<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::assert_examples;
+
+    // Each example is `(name, expected answer)`. The example named "" is `examples/DAY_FILE.txt`,
+    // others are read from e.g. `examples/DAY_FILE-2.txt` or `examples/DAY_FILE/part2.txt`.
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        assert_examples(DAY, part_one, &[("", None)]);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        assert_examples(DAY, part_two, &[("", None)]);
     }
 }
 "#;
@@ -39,13 +42,25 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(puzzle: PuzzleId) {
+    let PuzzleId { year, day } = puzzle;
+    let input_path = format!("data/{year}/inputs/{day}.txt");
+    let example_path = format!("data/{year}/examples/{day}.txt");
+    let module_path = format!("src/bin/{puzzle}.rs");
+
+    for dir in ["inputs", "examples"] {
+        if let Err(e) = fs::create_dir_all(format!("data/{year}/{dir}")) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -57,7 +72,9 @@ pub fn handle(day: Day) {
 
     match file.write_all(
         MODULE_TEMPLATE
+            .replace("YEAR_NUMBER", &year.to_string())
             .replace("DAY_NUMBER", &day.into_inner().to_string())
+            .replace("DAY_FILE", &day.to_string())
             .as_bytes(),
     ) {
         Ok(()) => {
@@ -89,6 +106,16 @@ pub fn handle(day: Day) {
         }
     }
 
+    // the description is there if the puzzle was downloaded before scaffolding.
+    if let Ok(description) = fs::read_to_string(aoc_client::get_puzzle_path(puzzle)) {
+        if let Err(e) = examples::update(puzzle, &description) {
+            eprintln!("Failed to write examples: {e}");
+        }
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!(
+        "🎄 Type `cargo solve {} --year {year}` to run your solution.",
+        day.into_inner()
+    );
 }
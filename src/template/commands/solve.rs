@@ -1,27 +1,100 @@
-use std::process::{Command, Stdio};
+use std::{
+    fs,
+    process::{self, Command, Stdio},
+};
 
-use crate::Day;
+use crate::template::{
+    file_path, registry,
+    runner::{arg_value, RunOptions},
+    ANSI_BOLD, ANSI_RESET,
+};
+use crate::{DaySet, PuzzleId, Year};
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// What to run of a solution, and whether to submit its answer.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    /// The part whose answer is submitted.
+    pub submit: Option<u8>,
+    /// Only runs this part if set.
+    pub part: Option<u8>,
+    /// A file to read the input from instead of the puzzle input, or `-` for stdin.
+    pub input: Option<String>,
+}
 
-    if release {
-        cmd_args.push("--release".to_string());
+impl Selection {
+    /// Reads the selection passed to a solution binary by [`command`].
+    #[must_use]
+    pub fn from_args() -> Self {
+        Self {
+            submit: arg_value("--submit").and_then(|x| x.parse().ok()),
+            part: arg_value("--part").and_then(|x| x.parse().ok()),
+            input: arg_value("--input"),
+        }
     }
 
-    cmd_args.push("--".to_string());
+    /// Whether the solution runs on the puzzle input, by default or because `--input` points to it.
+    /// Other inputs are never timed or submitted.
+    #[must_use]
+    pub fn is_puzzle_input(&self, puzzle: PuzzleId) -> bool {
+        match self.input.as_deref() {
+            None => true,
+            Some("-") => false,
+            Some(path) => match (
+                fs::canonicalize(path),
+                fs::canonicalize(file_path("inputs", puzzle)),
+            ) {
+                (Ok(path), Ok(puzzle_input)) => path == puzzle_input,
+                _ => false,
+            },
+        }
+    }
+}
 
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+pub fn handle(
+    year: Year,
+    days: &DaySet,
+    release: bool,
+    selection: Selection,
+    options: &RunOptions,
+) {
+    if selection.part.is_some_and(|part| part != 1 && part != 2) {
+        eprintln!("Expecting --part to be 1 or 2.");
+        process::exit(1);
+    }
+
+    if days.len() != 1 && selection.submit.is_some() {
+        eprintln!("Can only submit the answer of a single day.");
+        process::exit(1);
     }
 
-    if time {
-        cmd_args.push("--time".to_string());
+    if days.len() != 1 && selection.input.is_some() {
+        eprintln!("Can only pass an input to a single day.");
+        process::exit(1);
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+    for (i, day) in days.iter().enumerate() {
+        let puzzle = PuzzleId::new(year, day);
+
+        if days.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+            println!("------");
+
+            if registry::get(puzzle).is_none() {
+                println!("Not solved.");
+                continue;
+            }
+        }
+
+        run(puzzle, release, &selection, options);
+    }
+}
+
+fn run(puzzle: PuzzleId, release: bool, selection: &Selection, options: &RunOptions) {
+    let mut cmd = command(puzzle, release, selection, options)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
@@ -29,3 +102,45 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// The `cargo run` command that runs the solution binary of `puzzle`.
+pub(crate) fn command(
+    puzzle: PuzzleId,
+    release: bool,
+    selection: &Selection,
+    options: &RunOptions,
+) -> Command {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
+
+    if release {
+        cmd_args.push("--release".to_string());
+    }
+
+    if options.track_memory {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("alloc_tracking".to_string());
+    }
+
+    cmd_args.push("--".to_string());
+
+    if let Some(submit_part) = selection.submit {
+        cmd_args.push("--submit".to_string());
+        cmd_args.push(submit_part.to_string());
+    }
+
+    if let Some(part) = selection.part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    if let Some(input) = &selection.input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input.clone());
+    }
+
+    cmd_args.extend(options.to_args());
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(&cmd_args);
+    cmd
+}
@@ -0,0 +1,171 @@
+use std::{any::Any, process};
+
+use crate::template::{
+    commands::all::child_commands,
+    ledger::{self, Ledger},
+    panics,
+    registry::{self, PartFn},
+    try_read_file, ANSI_BOLD, ANSI_RESET,
+};
+use crate::{all_days, Day, PuzzleId, Year};
+
+enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    Missing,
+}
+
+impl Status {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Status::Pass => "✔",
+            Status::Fail { .. } => "✖",
+            Status::Missing => "?",
+        }
+    }
+}
+
+/// Runs every registered solution and compares its answers to the expected answers stored in the day's ledger.
+/// With `record`, parts that have no expected answer yet get the solution's current answer recorded as expected.
+pub fn handle(year: Year, is_release: bool, record: bool) {
+    if let Some(code) = child_commands::rerun_in_profile(is_release, false) {
+        process::exit(code);
+    }
+
+    let mut rows: Vec<(Day, [Status; 2])> = vec![];
+
+    for day in all_days() {
+        let puzzle = PuzzleId::new(year, day);
+
+        let Some(solution) = registry::get(puzzle) else {
+            continue;
+        };
+
+        let mut ledger = match Ledger::load(puzzle) {
+            Ok(ledger) => ledger,
+            Err(e) => {
+                eprintln!("{}: {e}", ledger::get_path(puzzle));
+                process::exit(1);
+            }
+        };
+
+        let has_expected =
+            ledger.part(1).expected().is_some() || ledger.part(2).expected().is_some();
+
+        // nothing to compare against, skip running the solution.
+        if !has_expected && !record {
+            rows.push((day, [Status::Missing, Status::Missing]));
+            continue;
+        }
+
+        let (part_1, part_2) = match try_read_file("inputs", puzzle) {
+            Ok(input) => {
+                println!("Verifying day {day}...");
+
+                match panics::catch(|| solution.prepare(input)) {
+                    Ok(input) => (
+                        run(solution.part_one, input.as_ref()),
+                        run(solution.part_two, input.as_ref()),
+                    ),
+                    Err(panic) => (Err(panic.to_string()), Err(panic.to_string())),
+                }
+            }
+            Err(e) => {
+                if has_expected {
+                    eprintln!("Could not read input for day {day}: {e}");
+                }
+                (Ok(None), Ok(None))
+            }
+        };
+
+        if record {
+            let mut is_changed = false;
+
+            for (part, actual) in [(1, &part_1), (2, &part_2)] {
+                let part_ledger = ledger.part_mut(part);
+
+                if let (None, Ok(Some(answer))) = (part_ledger.expected(), actual) {
+                    println!("Recorded {answer} as the answer to day {day} part {part}.");
+                    part_ledger.recorded = Some(answer.clone());
+                    is_changed = true;
+                }
+            }
+
+            if is_changed {
+                if let Err(e) = ledger.save(puzzle) {
+                    eprintln!("{}: {e}", ledger::get_path(puzzle));
+                    process::exit(1);
+                }
+            }
+        }
+
+        rows.push((
+            day,
+            [
+                compare(ledger.part(1).expected(), part_1),
+                compare(ledger.part(2).expected(), part_2),
+            ],
+        ));
+    }
+
+    println!();
+    println!("{ANSI_BOLD}Day | Part 1 | Part 2{ANSI_RESET}");
+    for (day, [part_1, part_2]) in &rows {
+        println!(" {day} |   {}    |   {}", part_1.symbol(), part_2.symbol());
+    }
+
+    let statuses = || rows.iter().flat_map(|(_, statuses)| statuses.iter());
+    let count = |f: fn(&Status) -> bool| statuses().filter(|s| f(s)).count();
+
+    let failed = count(|s| matches!(s, Status::Fail { .. }));
+
+    if failed > 0 {
+        println!();
+        for (day, statuses) in &rows {
+            for (i, status) in statuses.iter().enumerate() {
+                if let Status::Fail { expected, actual } = status {
+                    println!(
+                        "Day {day} part {}: expected {expected}, got {}",
+                        i + 1,
+                        actual.as_deref().unwrap_or("nothing")
+                    );
+                }
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{ANSI_BOLD}{} passed, {failed} failed, {} missing.{ANSI_RESET}",
+        count(|s| matches!(s, Status::Pass)),
+        count(|s| matches!(s, Status::Missing)),
+    );
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// Runs a part, returning its answer or the message it panicked with.
+fn run(part: PartFn, input: &dyn Any) -> Result<Option<String>, String> {
+    panics::catch(|| part(input))
+        .map(|answer| answer.map(|answer| answer.to_string()))
+        .map_err(|panic| panic.to_string())
+}
+
+/// Compares a part's result to the expected answer, reporting a panic in place of its answer.
+fn compare(expected: Option<&str>, actual: Result<Option<String>, String>) -> Status {
+    let actual = actual.unwrap_or_else(Some);
+
+    match expected {
+        None => Status::Missing,
+        Some(expected) if actual.as_deref().map(str::trim) == Some(expected.trim()) => Status::Pass,
+        Some(expected) => Status::Fail {
+            expected: expected.into(),
+            actual,
+        },
+    }
+}
@@ -0,0 +1,306 @@
+use std::{
+    fs,
+    io::{stdout, Write},
+    path::PathBuf,
+    process::{self, Command, Output},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use serde_json::Value;
+
+use crate::template::{
+    commands::solve::{self, Selection},
+    example_files, file_path,
+    runner::{OutputFormat, RunOptions},
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::{DaySet, PuzzleId, Year};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// The outcome of the example tests of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TestStatus {
+    Passed,
+    Failed,
+    Missing,
+}
+
+impl TestStatus {
+    fn symbol(self) -> &'static str {
+        match self {
+            TestStatus::Passed => "✔",
+            TestStatus::Failed => "✖",
+            TestStatus::Missing => "?",
+        }
+    }
+}
+
+/// Re-runs the example tests and the solution of a day whenever its source, examples or input change.
+pub fn handle(
+    year: Year,
+    days: &DaySet,
+    release: bool,
+    selection: &Selection,
+    options: &RunOptions,
+) {
+    let mut days = days.iter();
+    let (Some(day), None) = (days.next(), days.next()) else {
+        eprintln!("Can only watch a single day.");
+        process::exit(1);
+    };
+
+    if selection.part.is_some_and(|part| part != 1 && part != 2) {
+        eprintln!("Expecting --part to be 1 or 2.");
+        process::exit(1);
+    }
+
+    if selection.submit.is_some() {
+        eprintln!("Can not submit answers while watching.");
+        process::exit(1);
+    }
+
+    // stdin is used up by the first run and can't be watched for changes.
+    if selection.input.as_deref() == Some("-") {
+        eprintln!(
+            "Can not read the input from stdin while watching, pass a file to --input instead."
+        );
+        process::exit(1);
+    }
+
+    let puzzle = PuzzleId::new(year, day);
+    let options = RunOptions {
+        format: OutputFormat::Json,
+        ..options.clone()
+    };
+
+    let mut last_seen = None;
+
+    loop {
+        let seen = snapshot(puzzle, selection);
+
+        if last_seen.as_ref() != Some(&seen) {
+            last_seen = Some(seen);
+
+            print!("{CLEAR_SCREEN}");
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+            println!("------");
+            let _ = stdout().flush();
+
+            if let Some(tests) = run_tests(puzzle) {
+                run_solution(puzzle, release, selection, &options, tests);
+            }
+
+            println!(
+                "\n{ANSI_ITALIC}Watching for changes to the solution, examples and input. Press Ctrl-C to stop.{ANSI_RESET}"
+            );
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// The files that trigger a new run, along with when they were last modified.
+fn snapshot(puzzle: PuzzleId, selection: &Selection) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let input = match selection.input.as_deref() {
+        Some(path) => PathBuf::from(path),
+        None => file_path("inputs", puzzle),
+    };
+
+    let mut paths = vec![PathBuf::from(format!("src/bin/{puzzle}.rs")), input];
+    paths.extend(example_files(puzzle));
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Runs the tests of the solution and returns the status of each part,
+/// or nothing if the solution doesn't compile.
+fn run_tests(puzzle: PuzzleId) -> Option<[TestStatus; 2]> {
+    let output = match Command::new("cargo")
+        .args(["test", "--bin", &puzzle.to_string()])
+        .env("RUST_BACKTRACE", "0")
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            return None;
+        }
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let statuses = [1, 2].map(|part| test_status(&stdout, part));
+
+    if !output.status.success() && statuses.iter().all(|s| *s == TestStatus::Missing) {
+        print_failure(&output);
+        return None;
+    }
+
+    for (part, status) in (1..).zip(statuses) {
+        if status == TestStatus::Failed {
+            if let Some(message) = failure_message(&stdout, part) {
+                println!("{ANSI_ITALIC}Part {part} example: {message}{ANSI_RESET}");
+            }
+        }
+    }
+
+    Some(statuses)
+}
+
+fn test_name(part: u8) -> &'static str {
+    if part == 1 {
+        "tests::test_part_one"
+    } else {
+        "tests::test_part_two"
+    }
+}
+
+/// Finds the result of a part's test in the output of `cargo test`.
+fn test_status(output: &str, part: u8) -> TestStatus {
+    let prefix = format!("test {} ... ", test_name(part));
+
+    match output
+        .lines()
+        .find_map(|line| line.strip_prefix(&prefix))
+        .map(str::trim)
+    {
+        Some("ok") => TestStatus::Passed,
+        Some(_) => TestStatus::Failed,
+        None if output.contains(&format!("---- {} stdout ----", test_name(part))) => {
+            TestStatus::Failed
+        }
+        None => TestStatus::Missing,
+    }
+}
+
+/// The panic message of a part's failed test, e.g. the assertion that failed.
+fn failure_message(output: &str, part: u8) -> Option<String> {
+    let header = format!("---- {} stdout ----", test_name(part));
+    let section = output.split(&header).nth(1)?;
+
+    let message: Vec<&str> = section
+        .lines()
+        .skip_while(|line| !line.contains("panicked at"))
+        .skip(1)
+        .take_while(|line| {
+            !line.trim().is_empty()
+                && !line.starts_with("note:")
+                && !line.starts_with("stack backtrace:")
+        })
+        .collect();
+
+    Some(message.join(" "))
+}
+
+fn run_solution(
+    puzzle: PuzzleId,
+    release: bool,
+    selection: &Selection,
+    options: &RunOptions,
+    tests: [TestStatus; 2],
+) {
+    let output = match solve::command(puzzle, release, selection, options).output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            return;
+        }
+    };
+
+    let records: Vec<Value> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+
+    for record in &records {
+        let duration = record["duration_nanos"]
+            .as_u64()
+            .map(|nanos| format!(" ({:.1?})", Duration::from_nanos(nanos)))
+            .unwrap_or_default();
+
+        if record["step"] == "parse" {
+            match record["error"].as_str() {
+                Some(error) => println!("Parse: ✖ {error}"),
+                None => println!("Parse:{duration}"),
+            }
+            continue;
+        }
+
+        let Some(part) = record["part"].as_u64().and_then(|x| u8::try_from(x).ok()) else {
+            continue;
+        };
+
+        let result = match (record["status"].as_str(), record["answer"].as_str()) {
+            (Some("solved"), Some(answer)) => {
+                format!("{ANSI_BOLD}{answer}{ANSI_RESET}{duration}")
+            }
+            (Some("unsolved"), _) => "Not solved.".into(),
+            (status, _) => match record["error"].as_str() {
+                Some(error) => format!("✖ {error}"),
+                None => format!("✖ {}", status.unwrap_or("no result")),
+            },
+        };
+
+        let test = tests[usize::from(part - 1)];
+        println!(
+            "Part {part}: {result}   {ANSI_ITALIC}examples {}{ANSI_RESET}",
+            test.symbol()
+        );
+    }
+
+    if records.is_empty() || !output.status.success() {
+        print_failure(&output);
+    }
+}
+
+/// Prints what a failed command printed to stderr, e.g. compile errors.
+fn print_failure(output: &Output) {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.trim().is_empty() {
+        eprintln!("{}", stderr.trim_end());
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{failure_message, test_status, TestStatus};
+
+    const OUTPUT: &str = "
+running 2 tests
+test tests::test_part_one ... ok
+test tests::test_part_two ... FAILED
+
+failures:
+
+---- tests::test_part_two stdout ----
+
+thread 'tests::test_part_two' panicked at src/bin/2023-10.rs:335:9:
+assertion `left == right` failed: example \"2\" of 2023-10
+  left: Some(4)
+ right: Some(10)
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+";
+
+    #[test]
+    fn reads_test_results() {
+        assert_eq!(test_status(OUTPUT, 1), TestStatus::Passed);
+        assert_eq!(test_status(OUTPUT, 2), TestStatus::Failed);
+        assert_eq!(
+            test_status("error[E0308]: mismatched types", 1),
+            TestStatus::Missing
+        );
+
+        assert_eq!(
+            failure_message(OUTPUT, 2).unwrap(),
+            "assertion `left == right` failed: example \"2\" of 2023-10   left: Some(4)  right: Some(10)"
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Finds the examples of a puzzle and their answers in its description, as downloaded to `data/{year}/puzzles/{day}.md`.
+//! Examples are the code blocks introduced by a paragraph that mentions an example, and the answer to an example
+//! is the last emphasized code (e.g. `` `*142*` ``) that follows it.
+
+/// An example input, named like the examples read by [`crate::template::read_example`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+}
+
+/// The examples of a puzzle, and the expected answer of each part for them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzzleExamples {
+    pub examples: Vec<Example>,
+    /// For each part, the names of the examples that have an answer, along with that answer.
+    pub answers: [Vec<(String, String)>; 2],
+}
+
+impl PuzzleExamples {
+    /// The examples of `part` as a table for `assert_examples`, e.g. `&[("", Some(142))]`.
+    /// Answers that aren't numbers are left as `None`, to be filled in by hand.
+    #[must_use]
+    pub fn test_table(&self, part: u8) -> Option<String> {
+        let answers = &self.answers[usize::from(part - 1)];
+
+        if answers.is_empty() {
+            return None;
+        }
+
+        let rows: Vec<String> = answers
+            .iter()
+            .map(|(name, answer)| match answer.parse::<i128>() {
+                Ok(answer) => format!("({name:?}, Some({answer}))"),
+                Err(_) => format!("({name:?}, None)"),
+            })
+            .collect();
+
+        Some(format!("&[{}]", rows.join(", ")))
+    }
+}
+
+enum Event {
+    Block { input: String, is_example: bool },
+    Answer(String),
+}
+
+/// Extracts the examples and answers from the markdown description of a puzzle.
+#[must_use]
+pub fn extract(markdown: &str) -> PuzzleExamples {
+    let mut result = PuzzleExamples::default();
+
+    for (part, events) in split_parts(markdown).iter().enumerate() {
+        let mut examples: Vec<(String, Option<String>)> = vec![];
+
+        let has_example = events.iter().any(|event| {
+            matches!(
+                event,
+                Event::Block {
+                    is_example: true,
+                    ..
+                }
+            )
+        });
+
+        for event in events {
+            match event {
+                // without any introduction, the first block of the first part is likely the example.
+                Event::Block { input, is_example }
+                    if *is_example || (part == 0 && !has_example && examples.is_empty()) =>
+                {
+                    let name = name_example(&mut result.examples, input);
+                    examples.push((name, None));
+                }
+                Event::Answer(answer) => {
+                    if let Some((_, last_answer)) = examples.last_mut() {
+                        *last_answer = Some(answer.clone());
+                    } else if part == 1 {
+                        // the second part often reuses the example of the first part.
+                        if let Some(example) = result.examples.first() {
+                            examples.push((example.name.clone(), Some(answer.clone())));
+                        }
+                    }
+                }
+                Event::Block { .. } => {}
+            }
+        }
+
+        result.answers[part] = examples
+            .into_iter()
+            .filter_map(|(name, answer)| Some((name, answer?)))
+            .collect();
+    }
+
+    result
+}
+
+/// Names an example input, reusing the name of an identical example that was found before.
+/// The first example is named `""`, the ones after it `"2"`, `"3"` and so on.
+fn name_example(examples: &mut Vec<Example>, input: &str) -> String {
+    if let Some(example) = examples.iter().find(|example| example.input == input) {
+        return example.name.clone();
+    }
+
+    let name = match examples.len() {
+        0 => String::new(),
+        n => (n + 1).to_string(),
+    };
+
+    examples.push(Example {
+        name: name.clone(),
+        input: input.into(),
+    });
+
+    name
+}
+
+/// Splits the description into the events of each part, in order.
+fn split_parts(markdown: &str) -> [Vec<Event>; 2] {
+    let mut parts: [Vec<Event>; 2] = [vec![], vec![]];
+    let mut part = 0;
+    let mut block: Option<String> = None;
+    let mut paragraph = String::new();
+    let mut is_new_paragraph = true;
+
+    for line in markdown.lines() {
+        if let Some(input) = &mut block {
+            if line.trim_end() == "```" {
+                parts[part].push(Event::Block {
+                    input: block.take().unwrap_or_default(),
+                    is_example: paragraph.to_lowercase().contains("example"),
+                });
+                paragraph.clear();
+            } else {
+                input.push_str(line);
+                input.push('\n');
+            }
+            continue;
+        }
+
+        if line.starts_with("```") {
+            block = Some(String::new());
+        } else if line.starts_with("## --- Part Two") {
+            part = 1;
+            paragraph.clear();
+        } else {
+            // keep the last paragraph around, it introduces the block that follows.
+            if line.trim().is_empty() {
+                is_new_paragraph = true;
+            } else {
+                if is_new_paragraph {
+                    paragraph.clear();
+                    is_new_paragraph = false;
+                }
+                paragraph.push_str(line);
+                paragraph.push('\n');
+            }
+
+            parts[part].extend(emphasized_code(line).into_iter().map(Event::Answer));
+        }
+    }
+
+    parts
+}
+
+/// Finds emphasized code like `` `*142*` `` or ``*`142`*``, which is how puzzles highlight answers.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let mut answers = vec![];
+    let mut rest = line;
+
+    loop {
+        let (start, open, close) = match (rest.find("`*"), rest.find("*`")) {
+            (Some(code), Some(em)) if em < code => (em, "*`", "`*"),
+            (Some(code), _) => (code, "`*", "*`"),
+            (None, Some(em)) => (em, "*`", "`*"),
+            (None, None) => break,
+        };
+
+        let value_start = start + open.len();
+        let Some(len) = rest[value_start..].find(close) else {
+            break;
+        };
+
+        let value = rest[value_start..value_start + len].trim();
+        if !value.is_empty() {
+            answers.push(value.to_string());
+        }
+
+        rest = &rest[value_start + len + close.len()..];
+    }
+
+    answers
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{emphasized_code, extract, Example};
+
+    const DESCRIPTION: &str = "## --- Day 1: Trebuchet?! ---
+
+The document consists of lines of text. For example:
+
+```
+1abc2
+treb7uchet
+```
+
+In this example, the values are `12` and `77`. Adding these together produces `*89*`.
+
+## --- Part Two ---
+
+Some of the digits are spelled out. Here is a new example:
+
+```
+two1nine
+```
+
+In this example, the value is `*29*`.
+";
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        let found = extract(DESCRIPTION);
+
+        assert_eq!(
+            found.examples,
+            [
+                Example {
+                    name: String::new(),
+                    input: "1abc2\ntreb7uchet\n".into()
+                },
+                Example {
+                    name: "2".into(),
+                    input: "two1nine\n".into()
+                },
+            ]
+        );
+        assert_eq!(found.test_table(1).unwrap(), r#"&[("", Some(89))]"#);
+        assert_eq!(found.test_table(2).unwrap(), r#"&[("2", Some(29))]"#);
+    }
+
+    #[test]
+    fn reuses_the_first_example_for_part_two() {
+        let description = "For example:\n\n```\nabc\n```\n\nIt is `*3*`.\n\n## --- Part Two ---\n\nNow it is `*ABC*`.\n";
+        let found = extract(description);
+
+        assert_eq!(found.examples.len(), 1);
+        assert_eq!(found.test_table(1).unwrap(), r#"&[("", Some(3))]"#);
+        assert_eq!(found.test_table(2).unwrap(), r#"&[("", None)]"#);
+    }
+
+    #[test]
+    fn finds_emphasized_code() {
+        assert_eq!(
+            emphasized_code("total *`281`*, or `*142*` and `code` *em*"),
+            ["281", "142"]
+        );
+    }
+}
@@ -0,0 +1,337 @@
+/// A local stand-in for adventofcode.com, used to exercise `download`, `read` and `--submit` offline.
+/// Point the client at it by setting `AOC_BASE_URL` to [`FakeServer::url`].
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+}
+
+#[derive(Default)]
+struct Puzzle {
+    input: String,
+    description: String,
+    answers: [Option<String>; 2],
+}
+
+#[derive(Default)]
+struct State {
+    session: Option<String>,
+    puzzles: HashMap<(u16, u8), Puzzle>,
+    cooldown: Option<String>,
+    submissions: Vec<Submission>,
+}
+
+pub struct FakeServer {
+    url: String,
+    state: Arc<Mutex<State>>,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl FakeServer {
+    /// Starts a server on a random local port that accepts any session cookie.
+    #[must_use]
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("could not bind fake server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let state = Arc::new(Mutex::new(State::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let state = Arc::clone(&state);
+            let shutdown = Arc::clone(&shutdown);
+
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    if let Ok(stream) = stream {
+                        handle_connection(stream, &state);
+                    }
+                }
+            })
+        };
+
+        Self {
+            url,
+            state,
+            shutdown,
+            handle: Some(handle),
+        }
+    }
+
+    /// The base URL of the server, e.g. `http://127.0.0.1:4321`.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Only accept requests carrying this session cookie.
+    pub fn require_session(&self, session: &str) {
+        self.state.lock().unwrap().session = Some(session.into());
+    }
+
+    /// Serves `input` and the HTML `description` for a day.
+    pub fn add_puzzle(&self, year: u16, day: u8, input: &str, description: &str) {
+        let mut state = self.state.lock().unwrap();
+        let puzzle = state.puzzles.entry((year, day)).or_default();
+        puzzle.input = input.into();
+        puzzle.description = description.into();
+    }
+
+    /// Sets the answer that submissions for a part are checked against.
+    pub fn set_answer(&self, year: u16, day: u8, part: u8, answer: &str) {
+        let mut state = self.state.lock().unwrap();
+        let puzzle = state.puzzles.entry((year, day)).or_default();
+        puzzle.answers[usize::from(part - 1)] = Some(answer.into());
+    }
+
+    /// Answers every submission with "please wait" while set, e.g. `Some("42s")`.
+    pub fn set_cooldown(&self, wait: Option<&str>) {
+        self.state.lock().unwrap().cooldown = wait.map(ToString::to_string);
+    }
+
+    /// Every submission received so far, in order.
+    #[must_use]
+    pub fn submissions(&self) -> Vec<Submission> {
+        self.state.lock().unwrap().submissions.clone()
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // wake up the listener so it can observe the shutdown flag.
+        let _ = TcpStream::connect(self.url.trim_start_matches("http://"));
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    cookie: Option<String>,
+    body: String,
+}
+
+fn handle_connection(mut stream: TcpStream, state: &Mutex<State>) {
+    let Some(request) = read_request(&stream) else {
+        return;
+    };
+
+    let (status, body) = respond(&request, &mut state.lock().unwrap());
+
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        _ => "Method Not Allowed",
+    };
+
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.flush();
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let mut cookie = None;
+    let mut content_length = 0;
+
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+
+        let (name, value) = line.split_once(':')?;
+        match name.to_lowercase().as_str() {
+            "cookie" => {
+                cookie = value
+                    .split(';')
+                    .find_map(|c| c.trim().strip_prefix("session="))
+                    .map(ToString::to_string);
+            }
+            "content-length" => content_length = value.trim().parse().ok()?,
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(Request {
+        method,
+        path,
+        cookie,
+        body: String::from_utf8_lossy(&body).into(),
+    })
+}
+
+fn respond(request: &Request, state: &mut State) -> (u16, String) {
+    let authorized = match (&state.session, &request.cookie) {
+        (Some(expected), Some(cookie)) => expected == cookie,
+        (None, Some(_)) => true,
+        (_, None) => false,
+    };
+
+    if !authorized {
+        return (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.".into(),
+        );
+    }
+
+    // paths look like `/{year}/day/{day}` with an optional `/input` or `/answer` suffix.
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+
+    let (year, day, action) = match segments.as_slice() {
+        [year, "day", day] => (year, day, None),
+        [year, "day", day, action] => (year, day, Some(*action)),
+        _ => return (404, "404 Not Found".into()),
+    };
+
+    let (Ok(year), Ok(day)) = (year.parse::<u16>(), day.parse::<u8>()) else {
+        return (404, "404 Not Found".into());
+    };
+
+    if !state.puzzles.contains_key(&(year, day)) {
+        return (404, "404 Not Found".into());
+    }
+
+    match (request.method.as_str(), action) {
+        ("GET", None) => (
+            200,
+            page(&format!(
+                "<article class=\"day-desc\">{}</article>",
+                state.puzzles[&(year, day)].description
+            )),
+        ),
+        ("GET", Some("input")) => (200, state.puzzles[&(year, day)].input.clone()),
+        ("POST", Some("answer")) => submit(state, year, day, &request.body),
+        _ => (405, String::new()),
+    }
+}
+
+fn submit(state: &mut State, year: u16, day: u8, body: &str) -> (u16, String) {
+    let form: HashMap<&str, String> = body
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k, decode_form_value(v)))
+        .collect();
+
+    let (Some(part), Some(answer)) = (
+        form.get("level").and_then(|l| l.parse::<u8>().ok()),
+        form.get("answer"),
+    ) else {
+        return (400, String::new());
+    };
+
+    if !(1..=2).contains(&part) {
+        return (400, String::new());
+    }
+
+    state.submissions.push(Submission {
+        year,
+        day,
+        part,
+        answer: answer.clone(),
+    });
+
+    if let Some(wait) = &state.cooldown {
+        return (
+            200,
+            article(&format!("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {wait} left to wait.")),
+        );
+    }
+
+    let Some(expected) = &state.puzzles[&(year, day)].answers[usize::from(part - 1)] else {
+        return (
+            200,
+            article("You don't seem to be solving the right level.  Did you already complete it?"),
+        );
+    };
+
+    let message = if answer == expected {
+        "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to restoring snow operations.".into()
+    } else {
+        let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+            (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+            (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+            _ => "",
+        };
+
+        format!("That's not the right answer{hint}.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.")
+    };
+
+    (200, article(&message))
+}
+
+fn decode_form_value(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    None => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into()
+}
+
+fn article(message: &str) -> String {
+    page(&format!("<article><p>{message}</p></article>"))
+}
+
+fn page(main: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en-us\">\n<body>\n<main>\n{main}\n</main>\n</body>\n</html>"
+    )
+}
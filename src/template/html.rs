@@ -0,0 +1,319 @@
+//! Minimal HTML to markdown conversion for the pages served by adventofcode.com.
+//! Only the handful of tags used inside puzzle `<article>` elements are supported,
+//! everything else is reduced to its text content.
+
+/// Extracts the inner HTML of every `<article>` element in `html`.
+#[must_use]
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+
+        let Some(len) = rest[content_start..].find("</article>") else {
+            break;
+        };
+
+        articles.push(&rest[content_start..content_start + len]);
+        rest = &rest[content_start + len..];
+    }
+
+    articles
+}
+
+/// Converts every `<article>` element in `html` to markdown, separated by blank lines.
+#[must_use]
+pub fn articles_to_markdown(html: &str) -> String {
+    articles(html)
+        .into_iter()
+        .map(to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Converts `html` to its plain text content, collapsing whitespace.
+#[must_use]
+pub fn to_text(html: &str) -> String {
+    let mut text = String::new();
+
+    for token in tokenize(html) {
+        if let Token::Text(s) = token {
+            text.push_str(&decode_entities(s));
+        }
+    }
+
+    collapse_whitespace(&text).trim().to_string()
+}
+
+/// Converts an HTML fragment to markdown.
+#[must_use]
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut list_depth: usize = 0;
+    let mut in_pre = false;
+    let mut link_href: Option<String> = None;
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(s) => {
+                let text = decode_entities(s);
+                if in_pre {
+                    out.push_str(&text);
+                } else {
+                    let text = collapse_whitespace(&text);
+                    // avoid leading whitespace at the start of a block.
+                    if out.is_empty() || out.ends_with('\n') || out.ends_with(' ') {
+                        out.push_str(text.trim_start());
+                    } else {
+                        out.push_str(&text);
+                    }
+                }
+            }
+            Token::Open(tag, attrs) => match tag.as_str() {
+                "h1" | "h2" | "h3" => {
+                    end_block(&mut out);
+                    out.push_str("## ");
+                }
+                "p" => end_block(&mut out),
+                "pre" => {
+                    end_block(&mut out);
+                    out.push_str("```\n");
+                    in_pre = true;
+                }
+                "code" if !in_pre => out.push('`'),
+                "em" | "b" | "strong" if !in_pre => out.push('*'),
+                "ul" | "ol" => {
+                    if list_depth == 0 {
+                        end_block(&mut out);
+                    }
+                    list_depth += 1;
+                }
+                "li" => {
+                    end_line(&mut out);
+                    out.push_str(&"  ".repeat(list_depth.saturating_sub(1)));
+                    out.push_str("- ");
+                }
+                "a" => {
+                    link_href = attribute(attrs, "href").map(decode_entities);
+                    out.push('[');
+                }
+                "br" => out.push('\n'),
+                _ => {}
+            },
+            Token::Close(tag) => match tag.as_str() {
+                "h1" | "h2" | "h3" | "p" => end_block(&mut out),
+                "pre" => {
+                    in_pre = false;
+                    end_line(&mut out);
+                    out.push_str("```");
+                    end_block(&mut out);
+                }
+                "code" if !in_pre => out.push('`'),
+                "em" | "b" | "strong" if !in_pre => out.push('*'),
+                "ul" | "ol" => {
+                    list_depth = list_depth.saturating_sub(1);
+                    if list_depth == 0 {
+                        end_block(&mut out);
+                    }
+                }
+                "a" => {
+                    out.push(']');
+                    if let Some(href) = link_href.take() {
+                        out.push_str(&format!("({href})"));
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+
+    out.trim().to_string()
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open(String, &'a str),
+    Close(String),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if tag_start > 0 {
+            tokens.push(Token::Text(&rest[..tag_start]));
+        }
+
+        let Some(tag_len) = rest[tag_start..].find('>') else {
+            tokens.push(Token::Text(&rest[tag_start..]));
+            break;
+        };
+
+        let tag = &rest[tag_start + 1..tag_start + tag_len];
+        rest = &rest[tag_start + tag_len + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+        } else if !tag.starts_with('!') {
+            let tag = tag.trim_end_matches('/');
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open(name.to_lowercase(), attrs));
+        }
+    }
+
+    tokens
+}
+
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{name}="))? + name.len() + 1;
+    let value = &attrs[start..];
+
+    match value.chars().next()? {
+        quote @ ('"' | '\'') => value[1..].split(quote).next(),
+        _ => value.split_whitespace().next(),
+    }
+}
+
+fn decode_entities(s: &str) -> String {
+    if !s.contains('&') {
+        return s.to_string();
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            }?;
+            Some((c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut last_was_space = false;
+
+    for c in s.chars() {
+        if c.is_whitespace() {
+            if !last_was_space {
+                out.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            out.push(c);
+            last_was_space = false;
+        }
+    }
+
+    out
+}
+
+fn end_line(out: &mut String) {
+    let trimmed_len = out.trim_end_matches(' ').len();
+    out.truncate(trimmed_len);
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+fn end_block(out: &mut String) {
+    end_line(out);
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{articles, articles_to_markdown, to_markdown, to_text};
+
+    #[test]
+    fn extracts_articles() {
+        let html = "<main><article class=\"day-desc\"><p>a</p></article><p>x</p><article><p>b</p></article></main>";
+        assert_eq!(articles(html), vec!["<p>a</p>", "<p>b</p>"]);
+    }
+
+    #[test]
+    fn converts_puzzle_markup() {
+        let html = concat!(
+            "<h2>--- Day 1: Trebuchet?! ---</h2>",
+            "<p>The newly-improved <em>calibration document</em> consists of lines of text:</p>\n",
+            "<pre><code>1abc2\npqr3stu8vwx\n</code></pre>\n",
+            "<p>Adding these together produces <code><em>142</em></code>.</p>\n",
+            "<ul>\n<li>first</li>\n<li>a &lt;b&gt; &amp; <a href=\"/2023/day/1/input\">link</a></li>\n</ul>",
+        );
+
+        let expected = [
+            "## --- Day 1: Trebuchet?! ---",
+            "",
+            "The newly-improved *calibration document* consists of lines of text:",
+            "",
+            "```",
+            "1abc2",
+            "pqr3stu8vwx",
+            "```",
+            "",
+            "Adding these together produces `*142*`.",
+            "",
+            "- first",
+            "- a <b> & [link](/2023/day/1/input)",
+        ]
+        .join("\n");
+
+        assert_eq!(to_markdown(html), expected);
+    }
+
+    #[test]
+    fn joins_multiple_articles() {
+        let html = "<article><h2>One</h2></article><p>skip</p><article><h2>Two</h2></article>";
+        assert_eq!(articles_to_markdown(html), "## One\n\n## Two");
+    }
+
+    #[test]
+    fn converts_to_text() {
+        let html = "<p>That's the <em>right</em>\n answer! &quot;x&quot; &#39;y&#x27;</p>";
+        assert_eq!(to_text(html), "That's the right answer! \"x\" 'y'");
+    }
+}
@@ -0,0 +1,285 @@
+/// Keeps track of every answer submitted for a day, so known-wrong answers are never sent twice.
+/// It also holds answers recorded by `verify --record` without submitting them.
+/// The ledger for a day lives in `data/{year}/answers/{day}.json`, and can be edited by hand.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::aoc_client::Verdict;
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse answer ledger: {e}"),
+            Error::IO(e) => write!(f, "could not access answer ledger: {e}"),
+        }
+    }
+}
+
+/// The reason an answer is not submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved(String),
+    KnownWrong(Verdict),
+    TooHigh(i128),
+    TooLow(i128),
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with answer \"{answer}\".")
+            }
+            Rejection::KnownWrong(verdict) => {
+                write!(f, "this answer was already submitted ({verdict}).")
+            }
+            Rejection::TooHigh(bound) => {
+                write!(
+                    f,
+                    "the answer must be lower than {bound}, which was too high."
+                )
+            }
+            Rejection::TooLow(bound) => {
+                write!(
+                    f,
+                    "the answer must be higher than {bound}, which was too low."
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub submitted_at: u64,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartLedger {
+    /// The accepted answer, once known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+    /// An answer recorded without submitting it, e.g. for parts solved before the ledger existed.
+    /// Solutions are checked against it, but it never stops an answer from being submitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recorded: Option<String>,
+    /// The highest answer known to be too low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lower_bound: Option<i128>,
+    /// The lowest answer known to be too high.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upper_bound: Option<i128>,
+    #[serde(default)]
+    pub attempts: Vec<Attempt>,
+}
+
+impl PartLedger {
+    /// The answer solutions should give: the accepted one, or else the recorded one.
+    #[must_use]
+    pub fn expected(&self) -> Option<&str> {
+        self.correct.as_deref().or(self.recorded.as_deref())
+    }
+
+    /// Checks whether `answer` is worth submitting given the previous attempts.
+    pub fn check(&self, answer: &str) -> Result<(), Rejection> {
+        if let Some(correct) = &self.correct {
+            return Err(Rejection::AlreadySolved(correct.clone()));
+        }
+
+        if let Some(attempt) = self.attempts.iter().find(|attempt| {
+            attempt.answer == answer
+                && matches!(
+                    attempt.verdict,
+                    Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+                )
+        }) {
+            return Err(Rejection::KnownWrong(attempt.verdict.clone()));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            if let Some(bound) = self.upper_bound.filter(|&bound| value >= bound) {
+                return Err(Rejection::TooHigh(bound));
+            }
+
+            if let Some(bound) = self.lower_bound.filter(|&bound| value <= bound) {
+                return Err(Rejection::TooLow(bound));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the server's verdict for `answer`, tightening the bounds where possible.
+    pub fn record(&mut self, answer: &str, verdict: Verdict) {
+        let value = answer.parse::<i128>().ok();
+
+        match (&verdict, value) {
+            (Verdict::Correct, _) => self.correct = Some(answer.into()),
+            (Verdict::TooHigh, Some(value)) => {
+                self.upper_bound = Some(self.upper_bound.map_or(value, |b| b.min(value)));
+            }
+            (Verdict::TooLow, Some(value)) => {
+                self.lower_bound = Some(self.lower_bound.map_or(value, |b| b.max(value)));
+            }
+            _ => {}
+        }
+
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        self.attempts.push(Attempt {
+            answer: answer.into(),
+            verdict,
+            submitted_at,
+        });
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default)]
+    pub part_1: PartLedger,
+    #[serde(default)]
+    pub part_2: PartLedger,
+}
+
+impl Ledger {
+    /// Loads the ledger for `puzzle`, or an empty one if nothing was submitted yet.
+    pub fn load(puzzle: PuzzleId) -> Result<Self, Error> {
+        let path = get_path(puzzle);
+
+        if !Path::new(&path).exists() {
+            return Ok(Self::default());
+        }
+
+        serde_json::from_str(&fs::read_to_string(path)?).map_err(|e| Error::Parser(e.to_string()))
+    }
+
+    pub fn save(&self, puzzle: PuzzleId) -> Result<(), Error> {
+        let path = get_path(puzzle);
+
+        if let Some(parent) = Path::new(&path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(self).map_err(|e| Error::Parser(e.to_string()))?;
+        fs::write(path, json + "\n")?;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn part(&self, part: u8) -> &PartLedger {
+        if part == 1 {
+            &self.part_1
+        } else {
+            &self.part_2
+        }
+    }
+
+    pub fn part_mut(&mut self, part: u8) -> &mut PartLedger {
+        if part == 1 {
+            &mut self.part_1
+        } else {
+            &mut self.part_2
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path(puzzle: PuzzleId) -> String {
+    format!("data/{}/answers/{}.json", puzzle.year, puzzle.day)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartLedger, Rejection};
+    use crate::template::aoc_client::Verdict;
+
+    #[test]
+    fn accepts_anything_when_empty() {
+        let ledger = PartLedger::default();
+        assert_eq!(ledger.check("42"), Ok(()));
+        assert_eq!(ledger.check("abc"), Ok(()));
+    }
+
+    #[test]
+    fn rejects_known_wrong_answers() {
+        let mut ledger = PartLedger::default();
+        ledger.record("abc", Verdict::Incorrect);
+        ledger.record("def", Verdict::TooRecent(None));
+
+        assert_eq!(
+            ledger.check("abc"),
+            Err(Rejection::KnownWrong(Verdict::Incorrect))
+        );
+        assert_eq!(ledger.check("def"), Ok(()));
+    }
+
+    #[test]
+    fn rejects_answers_outside_bounds() {
+        let mut ledger = PartLedger::default();
+        ledger.record("100", Verdict::TooHigh);
+        ledger.record("200", Verdict::TooHigh);
+        ledger.record("10", Verdict::TooLow);
+        ledger.record("5", Verdict::TooLow);
+
+        assert_eq!(ledger.upper_bound, Some(100));
+        assert_eq!(ledger.lower_bound, Some(10));
+
+        assert_eq!(ledger.check("101"), Err(Rejection::TooHigh(100)));
+        assert_eq!(ledger.check("150"), Err(Rejection::TooHigh(100)));
+        assert_eq!(ledger.check("9"), Err(Rejection::TooLow(10)));
+        assert_eq!(ledger.check("-3"), Err(Rejection::TooLow(10)));
+        assert_eq!(ledger.check("50"), Ok(()));
+    }
+
+    #[test]
+    fn stores_correct_answer() {
+        let mut ledger = PartLedger::default();
+        ledger.record("50", Verdict::Correct);
+
+        assert_eq!(ledger.correct.as_deref(), Some("50"));
+        assert_eq!(
+            ledger.check("51"),
+            Err(Rejection::AlreadySolved("50".into()))
+        );
+    }
+
+    #[test]
+    fn expects_correct_over_recorded_answer() {
+        let mut ledger = PartLedger {
+            recorded: Some("42".into()),
+            ..PartLedger::default()
+        };
+
+        assert_eq!(ledger.expected(), Some("42"));
+        assert_eq!(ledger.check("43"), Ok(()));
+
+        ledger.record("50", Verdict::Correct);
+        assert_eq!(ledger.expected(), Some("50"));
+    }
+}
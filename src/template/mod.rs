@@ -1,36 +1,221 @@
-use crate::Day;
-use std::{env, fs};
+use crate::PuzzleId;
+use std::{
+    env,
+    fmt::Debug,
+    fs, io,
+    path::{Path, PathBuf},
+};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod aoc_client;
+pub mod bench;
+pub mod bench_history;
 pub mod commands;
+pub mod examples;
+#[cfg(feature = "test_lib")]
+pub mod fake_server;
+pub mod html;
+pub mod ledger;
+pub mod panics;
 pub mod readme_benchmarks;
+pub mod registry;
 pub mod runner;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file from `data/{year}/{folder}/{day}.txt` to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    let f = try_read_file(folder, puzzle);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Like [`read_file`], but returns an error instead of panicking if the file can't be read.
+pub fn try_read_file(folder: &str, puzzle: PuzzleId) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    fs::read_to_string(cwd.join(file_path(folder, puzzle)))
+}
+
+/// Where [`read_file`] reads from, relative to the root of the repository.
+pub(crate) fn file_path(folder: &str, puzzle: PuzzleId) -> PathBuf {
+    Path::new("data")
+        .join(puzzle.year.to_string())
+        .join(folder)
+        .join(format!("{}.txt", puzzle.day))
+}
+
+/// Reads a named example of a puzzle, for days with more than one example or a different example per part.
+/// The example named e.g. `2` is read from `data/{year}/examples/{day}-2.txt` or `data/{year}/examples/{day}/2.txt`.
+/// An empty name reads the default example in `data/{year}/examples/{day}.txt`.
+#[must_use]
+pub fn read_example(puzzle: PuzzleId, name: &str) -> String {
+    let f = try_read_example(puzzle, name);
+    f.expect("could not open example file")
+}
+
+/// Like [`read_example`], but returns an error instead of panicking if the example can't be read.
+pub fn try_read_example(puzzle: PuzzleId, name: &str) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    let paths = example_paths(puzzle, name);
+
+    // report the first location if the example is in neither.
+    let path = paths
+        .iter()
+        .find(|path| cwd.join(path).exists())
+        .unwrap_or(&paths[0]);
+
+    fs::read_to_string(cwd.join(path))
+}
+
+/// Runs `part` on each named example and asserts that it returns the expected answer.
+/// See [`read_example`] for where examples are read from.
+///
+/// # Panics
+/// If an example can't be read, or `part` returns something else than expected.
+pub fn assert_examples<T: PartialEq + Debug>(
+    puzzle: PuzzleId,
+    part: impl Fn(&str) -> T,
+    examples: &[(&str, T)],
+) {
+    for (name, expected) in examples {
+        let result = part(&read_example(puzzle, name));
+        assert_eq!(&result, expected, "example {name:?} of {puzzle}");
+    }
+}
+
+/// Every example file of a puzzle, named or not.
+pub(crate) fn example_files(puzzle: PuzzleId) -> Vec<PathBuf> {
+    let dir = Path::new("data")
+        .join(puzzle.year.to_string())
+        .join("examples");
+    let day = puzzle.day.to_string();
+
+    let files = |dir: &Path| -> Vec<PathBuf> {
+        fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect()
+    };
+
+    let mut paths: Vec<PathBuf> = files(&dir)
+        .into_iter()
+        .filter(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            name == format!("{day}.txt") || name.starts_with(&format!("{day}-"))
+        })
+        .chain(files(&dir.join(&day)))
+        .collect();
+
+    paths.sort();
+    paths
+}
+
+/// Where the example named `name` may be, relative to the root of the repository.
+/// New examples go in the first of them.
+pub(crate) fn example_paths(puzzle: PuzzleId, name: &str) -> Vec<PathBuf> {
+    let dir = Path::new("data")
+        .join(puzzle.year.to_string())
+        .join("examples");
+    let day = puzzle.day;
+
+    if name.is_empty() {
+        vec![dir.join(format!("{day}.txt"))]
+    } else {
+        vec![
+            dir.join(format!("{day}-{name}.txt")),
+            dir.join(day.to_string()).join(format!("{name}.txt")),
+        ]
+    }
+}
+
+/// Creates the constants `DAY` and `SOLUTION` and sets up the input and runner for each part.
+///
+/// By default, both parts take the puzzle input as a `&str`. Passing the name of a parse function,
+/// e.g. `solution!(2023, 17, parse)`, parses the input once and passes a reference to the result to
+/// both parts instead, so the parse step is timed on its own. The parsed type must be `'static`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        /// The current day.
-        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+    ($year:expr, $day:expr) => {
+        /// The current puzzle.
+        const DAY: advent_of_code::PuzzleId =
+            advent_of_code::PuzzleId::new(advent_of_code::year!($year), advent_of_code::day!($day));
+
+        /// Both parts of this solution, for running it in-process.
+        pub const SOLUTION: advent_of_code::template::registry::Solution =
+            advent_of_code::template::registry::Solution {
+                puzzle: DAY,
+                parse: None,
+                part_one: |input| {
+                    part_one(input.downcast_ref::<String>().expect("raw input")).map(Into::into)
+                },
+                part_two: |input| {
+                    part_two(input.downcast_ref::<String>().expect("raw input")).map(Into::into)
+                },
+            };
 
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
+            let input = read_input(DAY);
             run_part(part_one, &input, DAY, 1);
             run_part(part_two, &input, DAY, 2);
+            finish(DAY);
         }
     };
+    ($year:expr, $day:expr, $parse:ident) => {
+        /// The current puzzle.
+        const DAY: advent_of_code::PuzzleId =
+            advent_of_code::PuzzleId::new(advent_of_code::year!($year), advent_of_code::day!($day));
+
+        /// Both parts of this solution and its parse step, for running it in-process.
+        pub const SOLUTION: advent_of_code::template::registry::Solution =
+            advent_of_code::template::registry::Solution {
+                puzzle: DAY,
+                parse: Some(|input| Box::new($parse(input))),
+                part_one: |input| {
+                    part_one(input.downcast_ref().expect("parsed input")).map(Into::into)
+                },
+                part_two: |input| {
+                    part_two(input.downcast_ref().expect("parsed input")).map(Into::into)
+                },
+            };
+
+        fn main() {
+            use advent_of_code::template::runner::*;
+            let input = read_input(DAY);
+            let parsed = run_parse($parse, &input, DAY);
+            run_part(part_one, &parsed, DAY, 1);
+            run_part(part_two, &parsed, DAY, 2);
+            finish(DAY);
+        }
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::example_paths;
+    use crate::{day, year, PuzzleId};
+
+    #[test]
+    fn looks_up_named_examples() {
+        let puzzle = PuzzleId::new(year!(2023), day!(10));
+
+        assert_eq!(
+            example_paths(puzzle, ""),
+            [PathBuf::from("data/2023/examples/10.txt")]
+        );
+        assert_eq!(
+            example_paths(puzzle, "part2-b"),
+            [
+                PathBuf::from("data/2023/examples/10-part2-b.txt"),
+                PathBuf::from("data/2023/examples/10/part2-b.txt"),
+            ]
+        );
+    }
 }
@@ -0,0 +1,105 @@
+/// Runs solution steps under panic isolation, so a panicking part doesn't take the rest of the run down with it.
+/// Caught panics are recorded with their message and location instead of being printed to stderr.
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+/// A panic caught while running a step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// Where the panic happened, as `file:line:column`.
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static IS_CATCHING: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+/// Installs a panic hook that records panics on threads inside [`catch`] and defers to the
+/// previous hook everywhere else.
+fn install_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if IS_CATCHING.get() {
+                CAUGHT.set(Some(Panic {
+                    message: payload_message(info.payload()),
+                    location: info.location().map(ToString::to_string),
+                }));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".into()
+    }
+}
+
+/// Runs `func`, returning the panic instead of unwinding if it panics.
+pub fn catch<T>(func: impl FnOnce() -> T) -> Result<T, Panic> {
+    install_hook();
+
+    let was_catching = IS_CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    IS_CATCHING.set(was_catching);
+
+    result.map_err(|payload| {
+        CAUGHT.take().unwrap_or_else(|| Panic {
+            message: payload_message(payload.as_ref()),
+            location: None,
+        })
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::catch;
+
+    #[test]
+    fn catches_panics_with_location() {
+        let panic = catch(|| -> u32 { panic!("valid pipe map") }).unwrap_err();
+
+        assert_eq!(panic.message, "valid pipe map");
+        assert!(panic
+            .location
+            .unwrap()
+            .starts_with("src/template/panics.rs:"));
+        assert_eq!(catch(|| 42), Ok(42));
+    }
+
+    #[test]
+    fn catches_formatted_panics() {
+        let value = 7;
+        let panic = catch(|| -> u32 { panic!("unexpected value {value}") }).unwrap_err();
+        assert_eq!(panic.message, "unexpected value 7");
+        assert!(panic
+            .to_string()
+            .contains("panicked at src/template/panics.rs:"));
+    }
+}
@@ -2,7 +2,7 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::Day;
+use crate::{Day, PuzzleId, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -21,19 +21,39 @@ impl From<std::io::Error> for Error {
 #[derive(Clone)]
 pub struct Timings {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// Only set for solutions with a shared parse step.
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
+/// The formatted timing of a part: its median, and optionally the spread around it and what it allocated.
+#[derive(Clone)]
+pub struct PartTiming {
+    pub median: String,
+    pub spread: Option<String>,
+    pub memory: Option<String>,
+}
+
+impl From<&str> for PartTiming {
+    fn from(median: &str) -> Self {
+        Self {
+            median: median.into(),
+            spread: None,
+            memory: None,
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{puzzle}.rs")
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -58,71 +78,259 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
+fn construct_table(
+    prefix: &str,
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    peak_rss: Option<&str>,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // only show optional columns if there is something to show in them.
+    let parts = || {
+        timings
+            .iter()
+            .flat_map(|timing| [&timing.parse, &timing.part_1, &timing.part_2])
+            .flatten()
+    };
+    let has_parse = timings.iter().any(|timing| timing.parse.is_some());
+    let has_spread = parts().any(|part| part.spread.is_some());
+    let has_memory = parts().any(|part| part.memory.is_some());
+
+    let mut columns = vec![];
+    if has_parse {
+        columns.push("Parse");
+    }
+    columns.extend(["Part 1", "Part 2"]);
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if has_parse || has_spread || has_memory {
+        let cells: Vec<&str> = columns
+            .iter()
+            .flat_map(|column| {
+                let mut cells = vec![*column];
+                if has_spread {
+                    cells.push("±");
+                }
+                if has_memory {
+                    cells.push("Memory");
+                }
+                cells
+            })
+            .collect();
+
+        lines.push(format!("| Day | {} |", cells.join(" | ")));
+        lines.push(format!("| :---: |{}", " :---: |".repeat(cells.len())));
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
+
+    let format_part = |part: Option<PartTiming>| {
+        let part = part.unwrap_or_else(|| "-".into());
+        let mut cells = vec![format!("`{}`", part.median)];
+
+        if has_spread {
+            cells.push(format!("`{}`", part.spread.as_deref().unwrap_or("-")));
+        }
+
+        if has_memory {
+            cells.push(format!("`{}`", part.memory.as_deref().unwrap_or("-")));
+        }
+
+        cells.join(" | ")
+    };
+
+    for timing in timings.iter().cloned() {
+        let path = get_path_for_bin(PuzzleId::new(year, timing.day));
+
+        let mut cells = vec![];
+        if has_parse {
+            cells.push(format_part(timing.parse));
+        }
+        cells.push(format_part(timing.part_1));
+        cells.push(format_part(timing.part_2));
 
-    for timing in timings {
-        let path = get_path_for_bin(timing.day);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | {} |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            cells.join(" | ")
         ));
     }
 
     lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
+    match peak_rss {
+        Some(peak_rss) => lines.push(format!(
+            "**Total: {total_millis:.2}ms** · **Peak RSS: {peak_rss}**"
+        )),
+        None => lines.push(format!("**Total: {total_millis:.2}ms**")),
+    }
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+/// Reads the rows of an existing table back into timings, along with its peak RSS.
+fn parse_table(table: &str) -> (Vec<Timings>, Option<String>) {
+    let cells = |line: &str| -> Vec<String> {
+        line.trim()
+            .trim_matches('|')
+            .split('|')
+            .map(|cell| cell.trim().trim_matches('`').to_string())
+            .collect()
+    };
+
+    let Some(header) = table.lines().find(|line| line.starts_with("| Day |")) else {
+        return (vec![], None);
+    };
+    let columns = cells(header);
+
+    let timings = table
+        .lines()
+        .filter(|line| line.starts_with("| [Day "))
+        .filter_map(|line| {
+            let cells = cells(line);
+            let day = cells[0]
+                .strip_prefix("[Day ")?
+                .split(']')
+                .next()?
+                .parse()
+                .ok()?;
+
+            let mut timing = Timings {
+                day,
+                parse: None,
+                part_1: None,
+                part_2: None,
+                total_nanos: 0_f64,
+            };
+            let mut step = None;
+
+            for (column, cell) in columns.iter().zip(&cells).skip(1) {
+                let value = (cell != "-").then(|| cell.clone());
+
+                match column.as_str() {
+                    "Parse" => step = Some(&mut timing.parse),
+                    "Part 1" => step = Some(&mut timing.part_1),
+                    "Part 2" => step = Some(&mut timing.part_2),
+                    _ => {}
+                }
+
+                let Some(step) = step.as_mut() else {
+                    continue;
+                };
+
+                match (column.as_str(), step.as_mut()) {
+                    ("±", Some(part)) => part.spread = value,
+                    ("Memory", Some(part)) => part.memory = value,
+                    ("±" | "Memory", None) => {}
+                    _ => {
+                        **step = value.map(|median| PartTiming::from(median.as_str()));
+                    }
+                }
+            }
+
+            timing.total_nanos = [&timing.parse, &timing.part_1, &timing.part_2]
+                .into_iter()
+                .flatten()
+                .filter_map(|part| parse_nanos(&part.median))
+                .sum();
+
+            Some(timing)
+        })
+        .collect();
+
+    let peak_rss = table
+        .split("**Peak RSS: ")
+        .nth(1)
+        .and_then(|rest| rest.split("**").next())
+        .map(Into::into);
+
+    (timings, peak_rss)
+}
+
+/// Parses a duration formatted like `12.3µs` into nanoseconds.
+fn parse_nanos(duration: &str) -> Option<f64> {
+    let units = [("ns", 1.0), ("µs", 1e3), ("ms", 1e6), ("s", 1e9)];
+    let (value, factor) = units
+        .iter()
+        .find_map(|(unit, factor)| Some((duration.strip_suffix(unit)?, factor)))?;
+
+    value.parse::<f64>().ok().map(|value| value * factor)
+}
+
+/// Replaces the table in `s`. Days that are in the table but not in `timings` are kept,
+/// so the table can be updated from a run of only some of the days.
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    peak_rss: Option<&str>,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+
+    let (previous, previous_peak_rss) = parse_table(&s[positions.pos_start..positions.pos_end]);
+    let mut kept: Vec<Timings> = previous
+        .into_iter()
+        .filter(|previous| timings.iter().all(|timing| timing.day != previous.day))
+        .collect();
+
+    let total_millis =
+        total_millis + kept.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+    let peak_rss = match peak_rss {
+        None if !kept.is_empty() => previous_peak_rss,
+        peak_rss => peak_rss.map(Into::into),
+    };
+
+    kept.extend(timings);
+    kept.sort_by_key(|timing| timing.day);
+
+    let table = construct_table("##", year, kept, total_millis, peak_rss.as_deref());
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+pub fn update(
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    peak_rss: Option<&str>,
+) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis, peak_rss)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{parse_nanos, update_content, PartTiming, Timings, MARKER};
+    use crate::{day, year};
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
                 day: day!(1),
+                parse: None,
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
                 total_nanos: 3e+10,
             },
             Timings {
                 day: day!(2),
+                parse: None,
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
                 total_nanos: 7e+10,
             },
             Timings {
                 day: day!(4),
+                parse: None,
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
                 total_nanos: 9e+10,
@@ -134,28 +342,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0, None).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,7 +371,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0, None).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -172,9 +380,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023-01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +391,79 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_spread() {
+        let mut timings = get_mock_timings();
+        timings[0].part_1 = Some(PartTiming {
+            median: "10ms".into(),
+            spread: Some("1.5ms".into()),
+            memory: None,
+        });
+        timings[2].part_2 = None;
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2023), timings, 190.0, None).unwrap();
+
+        assert!(s.contains("| Day | Part 1 | ± | Part 2 | ± |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `10ms` | `1.5ms` | `20ms` | `-` |"));
+        assert!(s.contains("| [Day 4](./src/bin/2023-04.rs) | `40ms` | `-` | `-` | `-` |"));
+    }
+
+    #[test]
+    fn format_benchmarks_with_parse() {
+        let mut timings = get_mock_timings();
+        timings[1].parse = Some("5ms".into());
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2023), timings, 190.0, None).unwrap();
+
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |\n| :---: | :---: | :---: | :---: |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `5ms` | `30ms` | `40ms` |"));
+    }
+
+    #[test]
+    fn format_benchmarks_with_memory() {
+        let mut timings = get_mock_timings();
+        timings[0].part_2 = Some(PartTiming {
+            median: "20ms".into(),
+            spread: None,
+            memory: Some("1.5 KiB".into()),
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2023), timings, 190.0, Some("3.0 MiB")).unwrap();
+
+        assert!(s.contains("| Day | Part 1 | Memory | Part 2 | Memory |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `10ms` | `-` | `20ms` | `1.5 KiB` |"));
+        assert!(s.contains("**Total: 190.00ms** · **Peak RSS: 3.0 MiB**"));
+    }
+
+    #[test]
+    fn merges_partial_runs() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0, None).unwrap();
+
+        let rerun = vec![Timings {
+            day: day!(2),
+            parse: None,
+            part_1: Some("1ms".into()),
+            part_2: Some("2ms".into()),
+            total_nanos: 3e+6,
+        }];
+        update_content(&mut s, year!(2023), rerun, 3.0, None).unwrap();
+
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `1ms` | `2ms` |"));
+        assert!(s.contains("| [Day 4](./src/bin/2023-04.rs) | `40ms` | `50ms` |"));
+        assert!(s.contains("**Total: 123.00ms**"));
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_nanos("12.5µs"), Some(12_500.0));
+        assert_eq!(parse_nanos("1.5s"), Some(1.5e9));
+        assert_eq!(parse_nanos("-"), None);
+    }
 }
@@ -0,0 +1,178 @@
+//! Library-level registry of solutions, so that a single binary can run any day in-process.
+//! Every solution binary exposes a `SOLUTION` constant via the `solution!` macro. The main binary
+//! collects these at build time and hands them to [`register`].
+use std::{any::Any, fmt::Display, sync::OnceLock};
+
+use crate::{PuzzleId, Year};
+
+/// The answer to a puzzle part, keeping the type returned by the solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    Isize(isize),
+    Text(String),
+}
+
+macro_rules! impl_answer_from {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    usize => Usize,
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+    isize => Isize,
+    String => Text,
+);
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.into())
+    }
+}
+
+impl Answer {
+    /// The name of the type returned by the solution, e.g. `u32`.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::U8(_) => "u8",
+            Answer::U16(_) => "u16",
+            Answer::U32(_) => "u32",
+            Answer::U64(_) => "u64",
+            Answer::U128(_) => "u128",
+            Answer::Usize(_) => "usize",
+            Answer::I8(_) => "i8",
+            Answer::I16(_) => "i16",
+            Answer::I32(_) => "i32",
+            Answer::I64(_) => "i64",
+            Answer::I128(_) => "i128",
+            Answer::Isize(_) => "isize",
+            Answer::Text(_) => "string",
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::U8(x) => x.fmt(f),
+            Answer::U16(x) => x.fmt(f),
+            Answer::U32(x) => x.fmt(f),
+            Answer::U64(x) => x.fmt(f),
+            Answer::U128(x) => x.fmt(f),
+            Answer::Usize(x) => x.fmt(f),
+            Answer::I8(x) => x.fmt(f),
+            Answer::I16(x) => x.fmt(f),
+            Answer::I32(x) => x.fmt(f),
+            Answer::I64(x) => x.fmt(f),
+            Answer::I128(x) => x.fmt(f),
+            Answer::Isize(x) => x.fmt(f),
+            Answer::Text(x) => x.fmt(f),
+        }
+    }
+}
+
+/// A shared parse step with its output type erased.
+pub type ParseFn = fn(&str) -> Box<dyn Any>;
+
+/// A solution part with its input and answer types erased. Parts receive the output of the
+/// solution's parse step, or the raw input as a `String` if the solution has no parse step.
+pub type PartFn = fn(&dyn Any) -> Option<Answer>;
+
+/// Both parts of a puzzle's solution, and its parse step if it has one.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    pub parse: Option<ParseFn>,
+    pub part_one: PartFn,
+    pub part_two: PartFn,
+}
+
+impl Solution {
+    /// Prepares `input` for the parts, running the parse step if there is one.
+    #[must_use]
+    pub fn prepare(&self, input: String) -> Box<dyn Any> {
+        match self.parse {
+            Some(parse) => parse(&input),
+            None => Box::new(input),
+        }
+    }
+
+    /// Returns the function for `part` (1 or 2).
+    #[must_use]
+    pub fn part(&self, part: u8) -> PartFn {
+        if part == 1 {
+            self.part_one
+        } else {
+            self.part_two
+        }
+    }
+}
+
+static SOLUTIONS: OnceLock<&'static [Solution]> = OnceLock::new();
+
+/// Registers the available solutions. Only the first call has an effect.
+pub fn register(solutions: &'static [Solution]) {
+    let _ = SOLUTIONS.set(solutions);
+}
+
+/// Every registered solution.
+#[must_use]
+pub fn solutions() -> &'static [Solution] {
+    SOLUTIONS.get().copied().unwrap_or_default()
+}
+
+/// The registered solution for `puzzle`, if there is one.
+#[must_use]
+pub fn get(puzzle: PuzzleId) -> Option<&'static Solution> {
+    solutions()
+        .iter()
+        .find(|solution| solution.puzzle == puzzle)
+}
+
+/// Every registered solution of `year`.
+pub fn year(year: Year) -> impl Iterator<Item = &'static Solution> {
+    solutions()
+        .iter()
+        .filter(move |solution| solution.puzzle.year == year)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn answers_keep_their_type() {
+        assert_eq!(Answer::from(42_u32), Answer::U32(42));
+        assert_eq!(Answer::from(-7_i64).to_string(), "-7");
+        assert_eq!(Answer::from(u128::MAX).type_name(), "u128");
+        assert_eq!(Answer::from("#.#").to_string(), "#.#");
+    }
+}
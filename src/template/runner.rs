@@ -1,98 +1,654 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::alloc::{self, AllocStats};
+use crate::template::bench::{self, BenchConfig, BenchStats};
+use crate::template::panics::{self, Panic};
+use crate::template::{
+    aoc_client, commands::solve::Selection, ledger::Ledger, read_file, registry::Answer,
+    ANSI_ITALIC, ANSI_RESET,
+};
+use crate::PuzzleId;
+use std::error::Error;
 use std::fmt::Display;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{self, stdout, Write};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, fs, process};
+
+use serde::Serialize;
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// How results are reported: human-readable text, or one JSON record per part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    /// Reads the `--format` argument passed to a solution binary.
+    #[must_use]
+    pub fn from_args() -> Self {
+        arg_value("--format")
+            .and_then(|x| x.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => f.write_str("text"),
+            OutputFormat::Json => f.write_str("json"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an output format, `text` or `json`")
+    }
+}
+
+/// Controls how solution steps are run and reported.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Steps are only benched if this is set.
+    pub bench: Option<BenchConfig>,
+    pub format: OutputFormat,
+    /// Count allocations per step. Requires the `alloc_tracking` feature.
+    pub track_memory: bool,
+}
+
+impl RunOptions {
+    /// Reads the options passed to a solution binary of `puzzle`.
+    #[must_use]
+    pub fn from_args(puzzle: PuzzleId) -> Self {
+        let is_puzzle_input = Selection::from_args().is_puzzle_input(puzzle);
+        let is_timed = env::args().any(|x| x == "--time");
+        if is_timed && !is_puzzle_input {
+            static WARNING: Once = Once::new();
+            WARNING.call_once(|| {
+                eprintln!("Warning: not timing, the input is not the puzzle input.");
+            });
+        }
+
+        let bench = (is_timed && is_puzzle_input).then(|| {
+            let mut config = BenchConfig::default();
+
+            if let Some(millis) = arg_value("--bench-warmup").and_then(|x| x.parse().ok()) {
+                config.warmup = Duration::from_millis(millis);
+            }
+
+            if let Some(millis) = arg_value("--bench-time").and_then(|x| x.parse().ok()) {
+                config.budget = Duration::from_millis(millis);
+            }
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+            config.iterations = arg_value("--bench-iterations").and_then(|x| x.parse().ok());
+            config
+        });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+        let track_memory = env::args().any(|x| x == "--mem");
+        if track_memory && !alloc::is_enabled() {
+            static WARNING: Once = Once::new();
+            WARNING.call_once(|| {
+                eprintln!(
+                    "Warning: --mem needs the `alloc_tracking` feature, not tracking memory."
+                );
+            });
+        }
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+        Self {
+            bench,
+            format: OutputFormat::from_args(),
+            track_memory: track_memory && alloc::is_enabled(),
+        }
+    }
+
+    /// The arguments that make a solution binary use these options.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if let Some(bench) = &self.bench {
+            args.push("--time".into());
+            args.push("--bench-warmup".into());
+            args.push(bench.warmup.as_millis().to_string());
+            args.push("--bench-time".into());
+            args.push(bench.budget.as_millis().to_string());
+
+            if let Some(iterations) = bench.iterations {
+                args.push("--bench-iterations".into());
+                args.push(iterations.to_string());
+            }
+        }
+
+        if self.format != OutputFormat::Text {
+            args.push("--format".into());
+            args.push(self.format.to_string());
+        }
+
+        if self.track_memory {
+            args.push("--mem".into());
+        }
+
+        args
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// How long a step of a solution took, and what it allocated if memory is tracked.
+/// When benched, `duration` is the median of the samples.
+pub struct Timing {
+    pub duration: Duration,
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+    pub memory: Option<AllocStats>,
+}
+
+impl Timing {
+    /// The timing of a step that did not finish.
+    fn unfinished() -> Self {
+        Self {
+            duration: Duration::ZERO,
+            samples: 0,
+            stats: None,
+            memory: None,
+        }
+    }
+}
+
+/// The outcome of running a solution part.
+pub struct PartResult {
+    pub answer: Option<Answer>,
+    pub timing: Timing,
+    /// Set if the part panicked, in which case there is no answer.
+    pub panic: Option<Panic>,
+}
+
+/// Whether a part produced an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartStatus {
+    Solved,
+    /// The solution returned `None`.
+    Unsolved,
+    /// There is no input to run the solution on.
+    MissingInput,
+    /// The solution panicked.
+    Panicked,
+    /// The solution was given up on because it ran for too long.
+    TimedOut,
+}
+
+/// A machine-readable record of a part's result, printed as one JSON line per part.
+#[derive(Debug, Clone, Serialize)]
+pub struct PartRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: PartStatus,
+    pub answer: Option<String>,
+    pub answer_type: Option<&'static str>,
+    pub duration_nanos: u128,
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+    pub memory: Option<AllocStats>,
+    pub error: Option<String>,
+}
+
+impl PartRecord {
+    #[must_use]
+    pub fn new(puzzle: PuzzleId, part: u8, result: &PartResult) -> Self {
+        Self {
+            year: puzzle.year.into_inner(),
+            day: puzzle.day.into_inner(),
+            part,
+            status: if result.panic.is_some() {
+                PartStatus::Panicked
+            } else if result.answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            answer: result.answer.as_ref().map(ToString::to_string),
+            answer_type: result.answer.as_ref().map(Answer::type_name),
+            duration_nanos: result.timing.duration.as_nanos(),
+            samples: result.timing.samples,
+            stats: result.timing.stats,
+            memory: result.timing.memory,
+            error: result.panic.as_ref().map(ToString::to_string),
+        }
+    }
+
+    /// A record for a part that could not be run at all.
+    #[must_use]
+    pub fn not_run(puzzle: PuzzleId, part: u8, status: PartStatus) -> Self {
+        Self {
+            year: puzzle.year.into_inner(),
+            day: puzzle.day.into_inner(),
+            part,
+            status,
+            answer: None,
+            answer_type: None,
+            duration_nanos: 0,
+            samples: 0,
+            stats: None,
+            memory: None,
+            error: None,
+        }
+    }
+
+    /// Writes this record as a single line of JSON.
+    pub fn write_to(&self, out: &mut impl Write) -> io::Result<()> {
+        write_json(out, self)
+    }
+}
+
+/// A machine-readable record of the shared parse step, printed as one JSON line before the parts.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParseRecord {
+    pub year: u16,
+    pub day: u8,
+    pub step: &'static str,
+    pub duration_nanos: u128,
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+    pub memory: Option<AllocStats>,
+    pub error: Option<String>,
+}
+
+impl ParseRecord {
+    #[must_use]
+    pub fn new(puzzle: PuzzleId, timing: &Timing) -> Self {
+        Self {
+            year: puzzle.year.into_inner(),
+            day: puzzle.day.into_inner(),
+            step: "parse",
+            duration_nanos: timing.duration.as_nanos(),
+            samples: timing.samples,
+            stats: timing.stats,
+            memory: timing.memory,
+            error: None,
+        }
+    }
+
+    /// A record for a parse step that panicked or timed out.
+    #[must_use]
+    pub fn failed(puzzle: PuzzleId, error: &impl Display) -> Self {
+        Self {
+            error: Some(error.to_string()),
+            ..Self::new(puzzle, &Timing::unfinished())
+        }
+    }
+
+    /// Writes this record as a single line of JSON.
+    pub fn write_to(&self, out: &mut impl Write) -> io::Result<()> {
+        write_json(out, self)
+    }
+}
+
+/// Reads the input of a solution binary: the file passed with `--input`, stdin if that is `-`,
+/// or the puzzle input otherwise.
+#[must_use]
+pub fn read_input(puzzle: PuzzleId) -> String {
+    let input = match Selection::from_args().input.as_deref() {
+        None => return read_file("inputs", puzzle),
+        Some("-") => io::read_to_string(io::stdin()),
+        Some(path) => fs::read_to_string(path),
+    };
+
+    input.unwrap_or_else(|e| {
+        eprintln!("Could not read input: {e}");
+        process::exit(1);
+    })
+}
+
+/// Runs a part in a solution binary and submits its answer if asked to.
+/// Does nothing if only the other part should run, as selected with `--part`.
+pub fn run_part<I: Clone, T: Into<Answer>>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+) {
+    if Selection::from_args()
+        .part
+        .is_some_and(|selected| selected != part)
+    {
+        return;
+    }
+
+    let options = RunOptions::from_args(puzzle);
+    let result = solve_part(func, input, puzzle, part, &options);
+
+    if result.panic.is_some() {
+        HAS_PANICKED.store(true, Ordering::Relaxed);
+    }
+
+    if let Some(answer) = result.answer {
+        submit_result(answer, puzzle, part);
+    }
+}
+
+/// Runs the shared parse step of a solution and returns the parsed input for the parts.
+/// Exits if parsing panics, as there is nothing to run the parts on.
+pub fn run_parse<P>(parse: impl Fn(&str) -> P, input: &str, puzzle: PuzzleId) -> P {
+    let options = RunOptions::from_args(puzzle);
+
+    match solve_parse(parse, input, puzzle, &options) {
+        Ok((parsed, _)) => parsed,
+        Err(_) => process::exit(1),
+    }
+}
+
+/// Set once a part run by [`run_part`] panics, so the solution binary can exit with an error.
+static HAS_PANICKED: AtomicBool = AtomicBool::new(false);
+
+/// Finishes a solution binary: prints its peak resident set size if memory is tracked,
+/// and exits with an error if any part panicked.
+pub fn finish(puzzle: PuzzleId) {
+    let options = RunOptions::from_args(puzzle);
+
+    if options.track_memory && options.format == OutputFormat::Text {
+        if let Some(peak_rss) = alloc::peak_rss() {
+            println!("Peak RSS: {}", alloc::format_bytes(peak_rss));
+        }
+    }
+
+    if HAS_PANICKED.load(Ordering::Relaxed) {
+        process::exit(1);
+    }
+}
+
+/// A step of a solution: the shared parse step, or one of the parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => f.write_str("Parse"),
+            Step::Part(part) => write!(f, "Part {part}"),
+        }
+    }
+}
+
+/// Runs a solution part and reports its result as configured by `options`.
+pub fn solve_part<I: Clone, T: Into<Answer>>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult {
+    let result = time_part(func, input, options, |answer| {
+        let _ = report_progress(&mut stdout(), Step::Part(part), answer.as_ref(), options);
+    });
+
+    let _ = report_part(&mut stdout(), puzzle, part, &result, options);
+    result
+}
+
+/// Runs a solution part without reporting anything. `on_answer` is called with the answer of the first run,
+/// before the part is benched.
+pub fn time_part<I: Clone, T: Into<Answer>>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    options: &RunOptions,
+    on_answer: impl Fn(&Option<Answer>),
+) -> PartResult {
+    let func = |input: I| func(input).map(Into::into);
+
+    match run_timed(func, input, options, on_answer) {
+        Ok((answer, timing)) => PartResult {
+            answer,
+            timing,
+            panic: None,
+        },
+        Err(panic) => PartResult {
+            answer: None,
+            timing: Timing::unfinished(),
+            panic: Some(panic),
+        },
+    }
+}
+
+/// Runs the shared parse step of a solution and reports how long it took as configured by `options`.
+pub fn solve_parse<P>(
+    parse: impl Fn(&str) -> P,
+    input: &str,
+    puzzle: PuzzleId,
+    options: &RunOptions,
+) -> Result<(P, Timing), Panic> {
+    let outcome = time_parse(parse, input, options, || {
+        let _ = report_progress(&mut stdout(), Step::Parse, None, options);
+    });
+
+    let _ = report_parse(
+        &mut stdout(),
+        puzzle,
+        outcome.as_ref().map(|(_, timing)| timing),
+        options,
+    );
+    outcome
+}
+
+/// Runs the shared parse step of a solution without reporting anything. `on_parsed` is called after the
+/// first run, before the step is benched.
+pub fn time_parse<P>(
+    parse: impl Fn(&str) -> P,
+    input: &str,
+    options: &RunOptions,
+    on_parsed: impl Fn(),
+) -> Result<(P, Timing), Panic> {
+    run_timed(parse, input, options, |_| on_parsed())
+}
+
+/// Reports that a step finished its first run and, if timed, is being benched.
+/// Parts print their answer right away, so it can be read while benching.
+pub fn report_progress(
+    out: &mut impl Write,
+    step: Step,
+    answer: Option<&Answer>,
+    options: &RunOptions,
+) -> io::Result<()> {
+    if options.format != OutputFormat::Text {
+        return Ok(());
+    }
+
+    let is_timed = options.bench.is_some();
+
+    match step {
+        Step::Parse if is_timed => write!(out, "{step}:")?,
+        Step::Parse => return Ok(()),
+        Step::Part(_) => write_result(out, &answer, &step.to_string(), "")?,
+    }
+
+    if is_timed {
+        write!(out, " > {ANSI_ITALIC}benching{ANSI_RESET}")?;
+    }
+
+    out.flush()
+}
+
+/// Reports the result of a part as configured by `options`.
+pub fn report_part(
+    out: &mut impl Write,
+    puzzle: PuzzleId,
+    part: u8,
+    result: &PartResult,
+    options: &RunOptions,
+) -> io::Result<()> {
+    let step = Step::Part(part).to_string();
+
+    match options.format {
+        OutputFormat::Text => match &result.panic {
+            Some(panic) => writeln!(out, "\r{step}: ✖ {panic}"),
+            None => {
+                write_result(out, &result.answer, &step, &format_duration(&result.timing))?;
+                write_details(out, &result.timing)
+            }
+        },
+        OutputFormat::Json => PartRecord::new(puzzle, part, result).write_to(out),
+    }
+}
+
+/// Reports how long the shared parse step took, or how it panicked, as configured by `options`.
+pub fn report_parse(
+    out: &mut impl Write,
+    puzzle: PuzzleId,
+    outcome: Result<&Timing, &Panic>,
+    options: &RunOptions,
+) -> io::Result<()> {
+    match (outcome, options.format) {
+        (Ok(timing), OutputFormat::Text) => {
+            if options.bench.is_some() {
+                write!(out, "\r")?;
+            }
+            writeln!(out, "Parse:{}", format_duration(timing))?;
+            write_details(out, timing)
+        }
+        (Ok(timing), OutputFormat::Json) => ParseRecord::new(puzzle, timing).write_to(out),
+        (Err(panic), OutputFormat::Text) => writeln!(out, "\rParse: ✖ {panic}"),
+        (Err(panic), OutputFormat::Json) => ParseRecord::failed(puzzle, panic).write_to(out),
+    }
+}
+
+/// Reports that a step was given up on after running for `limit`.
+pub fn report_timeout(
+    out: &mut impl Write,
+    puzzle: PuzzleId,
+    step: Step,
+    limit: Duration,
+    options: &RunOptions,
+) -> io::Result<()> {
+    let error = format!("timed out after {limit:.1?}");
+
+    match (step, options.format) {
+        (_, OutputFormat::Text) => writeln!(out, "\r{step}: ✖ {error}"),
+        (Step::Parse, OutputFormat::Json) => ParseRecord::failed(puzzle, &error).write_to(out),
+        (Step::Part(part), OutputFormat::Json) => PartRecord {
+            error: Some(error),
+            ..PartRecord::not_run(puzzle, part, PartStatus::TimedOut)
+        }
+        .write_to(out),
+    }
+}
+
+/// Run a solution step. The behavior differs depending on whether we are timing the solution:
+///  1. if not, the function is executed once.
+///  2. if so, the function is benched after the first run, and its duration is the median of the samples.
+///
+/// If memory is tracked, allocations are counted during the first run.
+/// Panics are caught and returned instead.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    options: &RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> Result<(T, Timing), Panic> {
     let timer = Instant::now();
-    let result = func(input.clone());
+    let (result, memory) = panics::catch(|| {
+        if options.track_memory {
+            let (result, memory) = alloc::measure(|| func(input.clone()));
+            (result, Some(memory))
+        } else {
+            (func(input.clone()), None)
+        }
+    })?;
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    let timing = match &options.bench {
+        Some(config) => {
+            let stats = panics::catch(|| bench::run(&func, &input, config))?;
+
+            Timing {
+                duration: stats.median,
+                samples: stats.samples,
+                stats: Some(stats),
+                memory,
+            }
+        }
+        None => Timing {
+            duration: base_time,
+            samples: 1,
+            stats: None,
+            memory,
+        },
     };
 
-    (result, run.0, run.1)
+    Ok((result, timing))
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Reads the value following `name` from the arguments passed to a solution binary.
+pub(crate) fn arg_value(name: &str) -> Option<String> {
+    let mut args = env::args().skip_while(|x| x != name);
+    args.next()?;
+    args.next()
+}
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn format_duration(timing: &Timing) -> String {
+    let duration = timing.duration;
+    let samples = timing.samples;
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
+    match &timing.stats {
+        None => format!(" ({duration:.1?})"),
+        Some(stats) if stats.outliers > 0 => format!(
+            " ({duration:.1?} ± {:.1?} @ {samples} samples, {} outliers)",
+            stats.stddev, stats.outliers
+        ),
+        Some(stats) => format!(
+            " ({duration:.1?} ± {:.1?} @ {samples} samples)",
+            stats.stddev
         ),
-    );
-
-    let mut timers: Vec<Duration> = vec![];
-
-    for _ in 0..bench_iterations {
-        // need a clone here to make the borrow checker happy.
-        let cloned = input.clone();
-        let timer = Instant::now();
-        func(cloned);
-        timers.push(timer.elapsed());
     }
-
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+/// Writes benchmark statistics and allocations below a step's result, if there are any.
+fn write_details(out: &mut impl Write, timing: &Timing) -> io::Result<()> {
+    if let Some(stats) = &timing.stats {
+        writeln!(out, "  {ANSI_ITALIC}{stats}{ANSI_RESET}")?;
+    }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+    if let Some(memory) = &timing.memory {
+        writeln!(out, "  {ANSI_ITALIC}{memory}{ANSI_RESET}")?;
     }
+
+    Ok(())
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn write_result<T: Display>(
+    out: &mut impl Write,
+    result: &Option<T>,
+    part: &str,
+    duration_str: &str,
+) -> io::Result<()> {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
@@ -100,47 +656,64 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    write!(out, "{str}")
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    write!(out, "\r")?;
+                    writeln!(out, "{str}")?;
+                    writeln!(out, "{result}")
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    write!(out, "{str}")
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    write!(out, "\r")?;
+                    writeln!(out, "{str}")
                 }
             }
         }
         None => {
             if is_intermediate_result {
-                print!("{part}: ✖");
+                write!(out, "{part}: ✖")
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                write!(out, "\r")?;
+                writeln!(out, "{part}: ✖             ")
             }
         }
     }
 }
 
+/// Writes `record` as a single line of JSON.
+fn write_json(out: &mut impl Write, record: &impl Serialize) -> io::Result<()> {
+    match serde_json::to_string(record) {
+        Ok(json) => writeln!(out, "{json}"),
+        Err(e) => {
+            eprintln!("failed to serialize result: {e}");
+            Ok(())
+        }
+    }
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session cookie is available.
+///  3. the answer ledger does not already rule the answer out.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<aoc_client::Verdict, aoc_client::AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
         return None;
     }
 
+    if !Selection::from_args().is_puzzle_input(puzzle) {
+        eprintln!("Not submitting, the input is not the puzzle input.");
+        return None;
+    }
+
     if args.len() < 3 {
         eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
         process::exit(1);
@@ -157,11 +730,38 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    if let Err(e) = aoc_client::check() {
+        eprintln!("{e}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let answer = result.to_string();
+
+    let mut ledger = match Ledger::load(puzzle) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(rejection) = ledger.part(part).check(&answer) {
+        eprintln!("Not submitting \"{answer}\": {rejection}");
+        return None;
+    }
+
+    println!("Submitting result...");
+    let submission = aoc_client::submit(puzzle, part, &answer);
+
+    match &submission {
+        Ok(verdict) => {
+            ledger.part_mut(part).record(&answer, verdict.clone());
+            if let Err(e) = ledger.save(puzzle) {
+                eprintln!("failed to update answer ledger: {e}");
+            }
+        }
+        Err(e) => eprintln!("failed to submit result: {e}"),
+    }
+
+    Some(submission)
 }
@@ -0,0 +1,559 @@
+//! End-to-end tests for the command-line interface, including the commands that talk to the Advent of Code website.
+//! These run the real binaries against a local [`FakeServer`] inside a scratch directory.
+#![cfg(feature = "test_lib")]
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use advent_of_code::template::fake_server::{FakeServer, Submission};
+
+const SESSION: &str = "test-session";
+
+const DESCRIPTION: &str = concat!(
+    "<h2>--- Day 1: Trebuchet?! ---</h2>",
+    "<p>For example:</p>\n",
+    "<pre><code>1abc2\ntreb7uchet\n</code></pre>\n",
+    "<p>Adding these together produces <code><em>89</em></code>.</p>",
+);
+
+struct Workspace {
+    root: PathBuf,
+}
+
+impl Workspace {
+    fn new() -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let root = env::temp_dir().join(format!(
+            "aoc-cli-test-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+
+        fs::create_dir_all(root.join("data/2023/inputs")).unwrap();
+
+        Self { root }
+    }
+
+    fn read(&self, path: impl AsRef<Path>) -> String {
+        fs::read_to_string(self.root.join(path)).unwrap()
+    }
+
+    fn write(&self, path: impl AsRef<Path>, contents: &str) {
+        fs::write(self.root.join(path), contents).unwrap();
+    }
+
+    fn run(&self, bin: &str, args: &[&str], server: &FakeServer, session: Option<&str>) -> Output {
+        let mut cmd = Command::new(bin);
+        cmd.args(args)
+            .current_dir(&self.root)
+            .env("AOC_BASE_URL", server.url())
+            // keep a developer's real session file out of the picture.
+            .env("HOME", &self.root)
+            .env_remove("USERPROFILE");
+
+        match session {
+            Some(session) => cmd.env("AOC_SESSION", session),
+            None => cmd.env_remove("AOC_SESSION"),
+        };
+
+        cmd.output().unwrap()
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+fn server() -> FakeServer {
+    let server = FakeServer::start();
+    server.require_session(SESSION);
+    server.add_puzzle(2023, 1, "12\n34\n", DESCRIPTION);
+    server.set_answer(2023, 1, 1, "46");
+    server
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into()
+}
+
+#[test]
+fn download_writes_input_and_puzzle() {
+    let server = server();
+    let ws = Workspace::new();
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_advent_of_code"),
+        &["download", "1", "--year", "2023"],
+        &server,
+        Some(SESSION),
+    );
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(ws.read("data/2023/inputs/01.txt"), "12\n34\n");
+
+    let puzzle = ws.read("data/2023/puzzles/01.md");
+    assert!(puzzle.starts_with("## --- Day 1: Trebuchet?! ---"));
+    assert!(puzzle.contains("```\n1abc2\ntreb7uchet\n```"));
+    assert!(puzzle.contains("`*89*`"));
+}
+
+#[test]
+fn download_fails_without_session() {
+    let server = server();
+    let ws = Workspace::new();
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_advent_of_code"),
+        &["download", "1", "--year", "2023"],
+        &server,
+        None,
+    );
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("session cookie not found"));
+}
+
+#[test]
+fn download_fails_with_wrong_session() {
+    let server = server();
+    let ws = Workspace::new();
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_advent_of_code"),
+        &["download", "1", "--year", "2023"],
+        &server,
+        Some("someone-else"),
+    );
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("status 400"));
+}
+
+#[test]
+fn read_prints_and_writes_puzzle() {
+    let server = server();
+    let ws = Workspace::new();
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_advent_of_code"),
+        &["read", "--year", "2023", "1"],
+        &server,
+        Some(SESSION),
+    );
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(stdout(&output).contains("## --- Day 1: Trebuchet?! ---"));
+    assert!(ws.read("data/2023/puzzles/01.md").contains("For example:"));
+}
+
+#[test]
+fn submit_right_answer() {
+    let server = server();
+    let ws = Workspace::new();
+    ws.write("data/2023/inputs/01.txt", "12\n34\n");
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_2023-01"),
+        &["--submit", "1"],
+        &server,
+        Some(SESSION),
+    );
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(stdout(&output).contains("That's the right answer!"));
+    assert_eq!(
+        server.submissions(),
+        vec![Submission {
+            year: 2023,
+            day: 1,
+            part: 1,
+            answer: "46".into()
+        }]
+    );
+}
+
+#[test]
+fn submit_wrong_answers() {
+    let server = server();
+    let ws = Workspace::new();
+
+    for (input, expected) in [("99\n", "too high"), ("11\n", "too low")] {
+        ws.write("data/2023/inputs/01.txt", input);
+
+        let output = ws.run(
+            env!("CARGO_BIN_EXE_2023-01"),
+            &["--submit", "1"],
+            &server,
+            Some(SESSION),
+        );
+
+        assert!(stdout(&output).contains(&format!("your answer is {expected}")));
+    }
+}
+
+#[test]
+fn submit_too_recently() {
+    let server = server();
+    server.set_cooldown(Some("42s"));
+    let ws = Workspace::new();
+    ws.write("data/2023/inputs/01.txt", "12\n34\n");
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_2023-01"),
+        &["--submit", "1"],
+        &server,
+        Some(SESSION),
+    );
+
+    assert!(stdout(&output).contains("You have 42s left to wait."));
+}
+
+#[test]
+fn ledger_blocks_repeated_and_out_of_bounds_answers() {
+    let server = server();
+    let ws = Workspace::new();
+
+    let submit = |input: &str| {
+        ws.write("data/2023/inputs/01.txt", input);
+        ws.run(
+            env!("CARGO_BIN_EXE_2023-01"),
+            &["--submit", "1"],
+            &server,
+            Some(SESSION),
+        )
+    };
+
+    submit("99\n");
+    let repeated = submit("99\n");
+    let out_of_bounds = submit("1\n2\n3\n4\n5\n6\n7\n8\n9\n");
+
+    assert!(String::from_utf8_lossy(&repeated.stderr).contains("already submitted (too high)"));
+    assert!(String::from_utf8_lossy(&out_of_bounds.stderr).contains("must be lower than 99"));
+    assert_eq!(server.submissions().len(), 1);
+
+    submit("12\n34\n");
+    let ledger = ws.read("data/2023/answers/01.json");
+    assert!(ledger.contains("\"correct\": \"46\""));
+    assert!(ledger.contains("\"upper_bound\": 99"));
+}
+
+#[test]
+fn json_format_prints_one_record_per_part() {
+    let server = server();
+    let ws = Workspace::new();
+    ws.write("data/2023/inputs/01.txt", "12\n34\n");
+
+    let solve = ws.run(
+        env!("CARGO_BIN_EXE_2023-01"),
+        &["--format", "json"],
+        &server,
+        None,
+    );
+    let all = ws.run(
+        env!("CARGO_BIN_EXE_advent_of_code"),
+        &["all", "--year", "2023", "--format", "json"],
+        &server,
+        None,
+    );
+
+    for output in [&solve, &all] {
+        assert!(output.status.success(), "{output:?}");
+
+        let records: Vec<serde_json::Value> = stdout(output)
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        let part_one = &records[0];
+        assert_eq!(part_one["day"], 1);
+        assert_eq!(part_one["part"], 1);
+        assert_eq!(part_one["status"], "solved");
+        assert_eq!(part_one["answer"], "46");
+        assert_eq!(part_one["answer_type"], "u32");
+        assert_eq!(part_one["samples"], 1);
+        assert_eq!(records[1]["part"], 2);
+    }
+
+    let all = stdout(&all);
+    assert!(all.contains(r#""day":2,"part":1,"status":"missing_input""#));
+}
+
+#[test]
+fn panicking_part_does_not_stop_the_other() {
+    let server = server();
+    let ws = Workspace::new();
+    ws.write("data/2023/inputs/10.txt", ".S.\n");
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_2023-10"),
+        &["--format", "json"],
+        &server,
+        None,
+    );
+
+    assert!(!output.status.success());
+
+    let records: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert_eq!(records[0]["status"], "solved");
+    assert_eq!(records[1]["status"], "panicked");
+    assert!(records[1]["error"].as_str().unwrap().contains("2023-10.rs"));
+}
+
+#[test]
+fn all_gives_up_on_parts_that_run_too_long() {
+    let server = server();
+    let ws = Workspace::new();
+    ws.write("data/2023/inputs/01.txt", "12\n34\n");
+    // never reaches `ZZZ`.
+    ws.write("data/2023/inputs/08.txt", "L\n\nAAA = (AAA, AAA)\n");
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_advent_of_code"),
+        &[
+            "all",
+            "--year",
+            "2023",
+            "--format",
+            "json",
+            "--timeout",
+            "200",
+        ],
+        &server,
+        None,
+    );
+
+    assert!(!output.status.success());
+
+    let records: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .filter(|record: &serde_json::Value| record["day"] == 8)
+        .collect();
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["status"], "timed_out");
+    assert_eq!(records[0]["error"], "timed out after 200.0ms");
+    assert_eq!(records[1]["status"], "timed_out");
+    assert!(stdout(&output).contains(r#""day":1,"part":1,"status":"solved""#));
+}
+
+#[test]
+fn all_stops_timing_after_a_timeout() {
+    let server = server();
+    let ws = Workspace::new();
+    // never reaches `ZZZ`.
+    ws.write("data/2023/inputs/08.txt", "L\n\nAAA = (AAA, AAA)\n");
+    ws.write("data/2023/inputs/09.txt", "0 3 6 9 12 15\n");
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_advent_of_code"),
+        &[
+            "all",
+            "8-9",
+            "--year",
+            "2023",
+            "--format",
+            "json",
+            "--time",
+            "--timeout",
+            "200",
+        ],
+        &server,
+        None,
+    );
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("day 08 is still running in the background"));
+
+    let records: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .filter(|record: &serde_json::Value| record["day"] == 9)
+        .collect();
+
+    assert_eq!(records[0]["status"], "solved");
+    assert_eq!(records[0]["samples"], 1);
+    assert_eq!(records[0]["stats"], serde_json::Value::Null);
+}
+
+#[test]
+fn parallel_all_prints_days_in_order() {
+    let server = server();
+    let ws = Workspace::new();
+    ws.write("data/2023/inputs/01.txt", "12\n34\n");
+    ws.write("data/2023/inputs/10.txt", ".S.\n");
+
+    let steps = |args: &[&str]| -> Vec<(u64, u64, String)> {
+        let output = ws.run(env!("CARGO_BIN_EXE_advent_of_code"), args, &server, None);
+
+        stdout(&output)
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .map(|record| {
+                (
+                    record["day"].as_u64().unwrap(),
+                    record["part"].as_u64().unwrap_or_default(),
+                    record["status"].to_string(),
+                )
+            })
+            .collect()
+    };
+
+    let serial = steps(&["all", "--year", "2023", "--format", "json"]);
+    let parallel = steps(&["all", "--year", "2023", "--format", "json", "--jobs", "4"]);
+
+    assert!(serial.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    assert_eq!(serial, parallel);
+}
+
+#[test]
+fn verify_checks_answers_recorded_without_submitting() {
+    let server = server();
+    let ws = Workspace::new();
+    ws.write("data/2023/inputs/01.txt", "12\n34\n");
+
+    let verify = |args: &[&str]| {
+        let args = [&["verify", "--year", "2023"], args].concat();
+        ws.run(env!("CARGO_BIN_EXE_advent_of_code"), &args, &server, None)
+    };
+
+    let recorded = verify(&["--record"]);
+    assert!(recorded.status.success(), "{recorded:?}");
+    assert!(ws
+        .read("data/2023/answers/01.json")
+        .contains("\"recorded\": \"46\""));
+    assert!(server.submissions().is_empty());
+
+    // a change that breaks the solution for this input.
+    ws.write("data/2023/inputs/01.txt", "99\n");
+    let verified = verify(&[]);
+
+    assert!(!verified.status.success());
+    assert!(stdout(&verified).contains("Day 01 part 1: expected 46, got 99"));
+}
+
+#[test]
+fn runs_one_part_on_another_input_without_timing_or_submitting() {
+    let server = server();
+    let ws = Workspace::new();
+    ws.write("data/2023/inputs/01.txt", "12\n34\n");
+    ws.write("custom.txt", "55\n");
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_2023-01"),
+        &[
+            "--part",
+            "2",
+            "--input",
+            "custom.txt",
+            "--time",
+            "--submit",
+            "2",
+            "--format",
+            "json",
+        ],
+        &server,
+        Some(SESSION),
+    );
+
+    assert!(output.status.success(), "{output:?}");
+
+    let records: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0]["part"], 2);
+    assert_eq!(records[0]["answer"], "55");
+    assert_eq!(records[0]["samples"], 1);
+    assert!(server.submissions().is_empty());
+}
+
+#[test]
+fn times_and_submits_the_puzzle_input_passed_as_input() {
+    let server = server();
+    let ws = Workspace::new();
+    ws.write("data/2023/inputs/01.txt", "12\n34\n");
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_2023-01"),
+        &[
+            "--input",
+            "./data/2023/../2023/inputs/01.txt",
+            "--time",
+            "--bench-iterations",
+            "5",
+            "--submit",
+            "1",
+            "--format",
+            "json",
+        ],
+        &server,
+        Some(SESSION),
+    );
+
+    assert!(output.status.success(), "{output:?}");
+
+    let records: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+
+    assert_eq!(records[0]["samples"], 5);
+    assert_eq!(server.submissions().len(), 1);
+}
+
+#[test]
+fn watch_rejects_input_from_stdin() {
+    let server = server();
+    let ws = Workspace::new();
+
+    let output = ws.run(
+        env!("CARGO_BIN_EXE_advent_of_code"),
+        &["solve", "1", "--year", "2023", "--watch", "--input", "-"],
+        &server,
+        None,
+    );
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Can not read the input from stdin"));
+}
+
+#[test]
+fn scaffold_fills_in_examples_from_the_puzzle() {
+    let server = server();
+    let ws = Workspace::new();
+    fs::create_dir_all(ws.root.join("src/bin")).unwrap();
+
+    for args in [
+        &["download", "1", "--year", "2023"][..],
+        &["scaffold", "1", "--year", "2023"],
+    ] {
+        let output = ws.run(
+            env!("CARGO_BIN_EXE_advent_of_code"),
+            args,
+            &server,
+            Some(SESSION),
+        );
+        assert!(output.status.success(), "{output:?}");
+    }
+
+    assert_eq!(ws.read("data/2023/examples/01.txt"), "1abc2\ntreb7uchet\n");
+
+    let module = ws.read("src/bin/2023-01.rs");
+    assert!(module.contains(r#"assert_examples(DAY, part_one, &[("", Some(89))]);"#));
+    assert!(module.contains(r#"assert_examples(DAY, part_two, &[("", None)]);"#));
+}